@@ -1,15 +1,117 @@
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, anyhow, bail};
 use indicatif::{ProgressBar, ProgressStyle};
 use reqwest::header::{HeaderMap, HeaderValue};
-use std::time::Duration;
+use reqwest::{Method, StatusCode};
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use crate::rate_limit::RateLimiter;
 use crate::settings::Settings;
 
 #[derive(Debug)]
 pub struct Client {
     http: reqwest::Client,
+    /// Client for presigned storage URLs; never carries the API key.
+    storage: reqwest::Client,
     base_url: String,
     show_spinner: bool,
+    retry: RetryPolicy,
+    limiter: Option<RateLimiter>,
+}
+
+/// When and how long to wait before retrying a failed request.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    /// Retry POST/PATCH on 5xx and connection errors too. 429 responses are
+    /// always retried since the server did not process the request.
+    pub retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            max_retries: settings.max_retries,
+            base_delay: Duration::from_millis(settings.retry_backoff_ms),
+            max_delay: Duration::from_millis(settings.retry_max_backoff_ms),
+            jitter: settings.retry_jitter,
+            retry_non_idempotent: settings.retry_non_idempotent,
+        }
+    }
+
+    /// Exponential backoff for the given (zero-based) retry attempt, capped at
+    /// `max_delay`. With jitter, the delay is drawn from `[delay / 2, delay]`.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if !self.jitter || delay.is_zero() {
+            return delay;
+        }
+        let half = delay / 2;
+        let random = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        half + Duration::from_nanos(random % (half.as_nanos() as u64 + 1))
+    }
+
+    /// Delay before the next attempt: `Retry-After` or `X-RateLimit-Reset`
+    /// when the server sent one, exponential backoff otherwise. Either way
+    /// the wait is capped at `max_delay`.
+    fn delay(&self, attempt: u32, headers: &HeaderMap) -> Duration {
+        server_delay(headers)
+            .map(|delay| delay.min(self.max_delay))
+            .unwrap_or_else(|| self.backoff(attempt))
+    }
+}
+
+/// Reads the wait time requested by the server, if any.
+///
+/// `Retry-After` is given in seconds. `X-RateLimit-Reset` is either a Unix
+/// timestamp or a number of seconds, depending on the deployment.
+fn server_delay(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(secs) = header_u64(headers, "Retry-After") {
+        return Some(Duration::from_secs(secs));
+    }
+    rate_limit_reset(headers)
+}
+
+/// Reads `X-RateLimit-Reset` as the time left until the quota resets.
+fn rate_limit_reset(headers: &HeaderMap) -> Option<Duration> {
+    let reset = header_u64(headers, "X-RateLimit-Reset")?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    // Anything past 2001-09-09 is a timestamp rather than a delay.
+    if reset > 1_000_000_000 {
+        Some(Duration::from_secs(reset.saturating_sub(now)))
+    } else {
+        Some(Duration::from_secs(reset))
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+    )
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 500 | 502 | 503 | 504)
+}
+
+fn is_transient_error(err: &reqwest::Error) -> bool {
+    err.is_connect() || err.is_timeout() || err.is_request()
 }
 
 impl Client {
@@ -27,6 +129,13 @@ impl Client {
         let http = reqwest::Client::builder()
             .default_headers(headers)
             .timeout(Duration::from_secs(settings.timeout))
+            .redirect(reqwest::redirect::Policy::custom(same_host_redirects))
+            .build()
+            .context("failed to build HTTP client")?;
+
+        // No overall timeout: uploads and downloads can take longer than an API call.
+        let storage = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(settings.timeout))
             .build()
             .context("failed to build HTTP client")?;
 
@@ -34,8 +143,11 @@ impl Client {
 
         Ok(Self {
             http,
+            storage,
             base_url,
             show_spinner: !json_mode,
+            retry: RetryPolicy::from_settings(settings),
+            limiter: RateLimiter::per_minute(settings.rate_limit),
         })
     }
 
@@ -55,6 +167,21 @@ impl Client {
         Some(pb)
     }
 
+    fn progress_bar(&self, len: u64, message: &str) -> Option<ProgressBar> {
+        if !self.show_spinner {
+            return None;
+        }
+        let pb = ProgressBar::new(len);
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("{msg} [{bar:30}] {bytes}/{total_bytes} ({eta})")
+                .unwrap()
+                .progress_chars("=> "),
+        );
+        pb.set_message(message.to_string());
+        Some(pb)
+    }
+
     pub async fn get(&self, path: &str) -> Result<serde_json::Value> {
         self.get_with_params(path, &[]).await
     }
@@ -64,33 +191,317 @@ impl Client {
         path: &str,
         params: &[(&str, &str)],
     ) -> Result<serde_json::Value> {
-        let spinner = self.spinner("Fetching...");
-        let url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
-        let response = self
-            .http
-            .get(&url)
-            .query(params)
-            .send()
+        self.send(Method::GET, path, params, None, "Fetching...")
             .await
-            .context("GET request failed")?;
-        let result = handle_response(response).await;
-        if let Some(pb) = spinner {
+    }
+
+    /// Fetches a cursor-paginated list endpoint page by page, following
+    /// `next_cursor` while `next_page_results` is true or until `limit` items
+    /// have been seen. `on_page` receives each page's items as they arrive.
+    /// Returns the total number of items passed to `on_page`.
+    pub async fn paginate<F>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        cursor: Option<&str>,
+        limit: Option<usize>,
+        mut on_page: F,
+    ) -> Result<usize>
+    where
+        F: FnMut(&[serde_json::Value]) -> Result<()>,
+    {
+        let mut cursor = cursor.map(str::to_string);
+        let mut seen = 0;
+
+        loop {
+            let mut query = params.to_vec();
+            if let Some(c) = &cursor {
+                query.push(("cursor", c));
+            }
+            let data = self.get_with_params(path, &query).await?;
+            let items = match data.as_array() {
+                Some(arr) => arr,
+                None => data["results"]
+                    .as_array()
+                    .context("unexpected response format: missing 'results' array")?,
+            };
+
+            let take = limit.map_or(items.len(), |l| items.len().min(l - seen));
+            on_page(&items[..take])?;
+            seen += take;
+
+            if limit.is_some_and(|l| seen >= l) || items.is_empty() {
+                break;
+            }
+            match data["next_cursor"].as_str() {
+                Some(next) if data["next_page_results"].as_bool() == Some(true) => {
+                    cursor = Some(next.to_string());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(seen)
+    }
+
+    /// Fetches every page of a paginated list, 100 items at a time.
+    pub async fn fetch_all(&self, path: &str) -> Result<Vec<serde_json::Value>> {
+        let mut items = Vec::new();
+        self.paginate(path, &[("per_page", "100")], None, None, |page| {
+            items.extend_from_slice(page);
+            Ok(())
+        })
+        .await?;
+        Ok(items)
+    }
+
+    pub async fn post(&self, path: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        self.send(Method::POST, path, &[], Some(body), "Sending...")
+            .await
+    }
+
+    pub async fn patch(&self, path: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        self.send(Method::PATCH, path, &[], Some(body), "Sending...")
+            .await
+    }
+
+    /// Sends a DELETE request. Returns `Value::Null` for empty (204) responses.
+    pub async fn delete(&self, path: &str) -> Result<serde_json::Value> {
+        self.send(Method::DELETE, path, &[], None, "Deleting...")
+            .await
+    }
+
+    /// Uploads `data` to a presigned storage URL as a multipart form with the
+    /// given credential `fields`, showing a progress bar. The API key is not sent.
+    pub async fn upload(
+        &self,
+        url: &str,
+        fields: &serde_json::Map<String, serde_json::Value>,
+        file_name: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let len = data.len() as u64;
+        let pb = self.progress_bar(len, &format!("Uploading {file_name}"));
+
+        let progress = pb.clone();
+        let chunks: Vec<Vec<u8>> = data.chunks(64 * 1024).map(<[u8]>::to_vec).collect();
+        let stream = futures_util::stream::iter(chunks.into_iter().map(move |chunk| {
+            if let Some(pb) = &progress {
+                pb.inc(chunk.len() as u64);
+            }
+            Ok::<_, std::io::Error>(chunk)
+        }));
+
+        let mut form = reqwest::multipart::Form::new();
+        for (key, value) in fields {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            form = form.text(key.clone(), value);
+        }
+        let part =
+            reqwest::multipart::Part::stream_with_length(reqwest::Body::wrap_stream(stream), len)
+                .file_name(file_name.to_string())
+                .mime_str(content_type)
+                .context("invalid content type")?;
+        form = form.part("file", part);
+
+        let result = self.storage.post(url).multipart(form).send().await;
+        if let Some(pb) = pb {
+            pb.finish_and_clear();
+        }
+        let response = result.context("upload failed")?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!("upload failed ({status}): {body}");
+        }
+        Ok(())
+    }
+
+    /// Downloads a file served at an API path into `dest`, showing a progress
+    /// bar. A redirect to another host (presigned storage) is followed without
+    /// the API key. The body is written to a `.part` file next to `dest` and
+    /// renamed on success, so a failed download leaves nothing behind.
+    /// Returns the number of bytes written.
+    pub async fn download(&self, path: &str, dest: &Path) -> Result<u64> {
+        let response = self
+            .send_raw(Method::GET, path, &[], None, "Fetching...")
+            .await?;
+
+        let response = if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .context("redirect without a Location header")?;
+            let url = response
+                .url()
+                .join(location)
+                .context("invalid redirect location")?;
+            let response = self
+                .storage
+                .get(url)
+                .send()
+                .await
+                .context("download failed")?;
+            let status = response.status();
+            if !status.is_success() {
+                bail!("download failed ({status})");
+            }
+            response
+        } else if response.status().is_success() {
+            response
+        } else {
+            return Err(response_error(response).await);
+        };
+
+        let name = dest
+            .file_name()
+            .map_or_else(String::new, |n| n.to_string_lossy().into_owned());
+        let part = dest.with_file_name(format!(".{name}.part"));
+        let written = match self.write_body(response, &part, &name).await {
+            Ok(written) => written,
+            Err(err) => {
+                let _ = std::fs::remove_file(&part);
+                return Err(err);
+            }
+        };
+        std::fs::rename(&part, dest)
+            .with_context(|| format!("failed to write {}", dest.display()))?;
+        Ok(written)
+    }
+
+    /// Streams a response body into `path` with a progress bar labeled `name`.
+    async fn write_body(
+        &self,
+        mut response: reqwest::Response,
+        path: &Path,
+        name: &str,
+    ) -> Result<u64> {
+        let mut file = std::fs::File::create(path)
+            .with_context(|| format!("failed to create {}", path.display()))?;
+        let pb = self.progress_bar(
+            response.content_length().unwrap_or(0),
+            &format!("Downloading {name}"),
+        );
+
+        let mut written = 0;
+        let result = async {
+            while let Some(chunk) = response.chunk().await.context("download failed")? {
+                file.write_all(&chunk)
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+                written += chunk.len() as u64;
+                if let Some(pb) = &pb {
+                    pb.inc(chunk.len() as u64);
+                }
+            }
+            Ok(written)
+        }
+        .await;
+        if let Some(pb) = pb {
             pb.finish_and_clear();
         }
         result
     }
 
-    pub async fn post(&self, path: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
-        let spinner = self.spinner("Sending...");
+    /// Waits for the client-side rate limiter to hand out a request slot.
+    async fn throttle(&self, spinner: Option<&ProgressBar>, message: &str) {
+        let Some(limiter) = &self.limiter else {
+            return;
+        };
+        let wait = limiter.reserve(Instant::now());
+        if wait.is_zero() {
+            return;
+        }
+        if let Some(pb) = spinner {
+            pb.set_message(format!("Throttling for {:.1}s...", wait.as_secs_f64()));
+        }
+        tokio::time::sleep(wait).await;
+        if let Some(pb) = spinner {
+            pb.set_message(message.to_string());
+        }
+    }
+
+    /// Sends a request and parses the JSON response, mapping error statuses
+    /// to errors.
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        params: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+        message: &str,
+    ) -> Result<serde_json::Value> {
+        let response = self.send_raw(method, path, params, body, message).await?;
+        handle_response(response).await
+    }
+
+    /// Sends a request, retrying rate-limited, transient server and connection
+    /// failures according to the retry policy. Returns the final response
+    /// whatever its status.
+    async fn send_raw(
+        &self,
+        method: Method,
+        path: &str,
+        params: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+        message: &str,
+    ) -> Result<reqwest::Response> {
+        let spinner = self.spinner(message);
         let url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
-        let response = self
-            .http
-            .post(&url)
-            .json(body)
-            .send()
-            .await
-            .context("POST request failed")?;
-        let result = handle_response(response).await;
+        let retryable = is_idempotent(&method) || self.retry.retry_non_idempotent;
+
+        let mut attempt = 0;
+        let result = loop {
+            let mut request = self.http.request(method.clone(), &url).query(params);
+            if let Some(b) = body {
+                request = request.json(b);
+            }
+
+            self.throttle(spinner.as_ref(), message).await;
+
+            let can_retry = attempt < self.retry.max_retries;
+            let delay = match request.send().await {
+                Ok(response) => {
+                    if let Some(limiter) = &self.limiter {
+                        limiter.observe(
+                            header_u64(response.headers(), "X-RateLimit-Remaining"),
+                            rate_limit_reset(response.headers()),
+                            Instant::now(),
+                        );
+                    }
+                    let status = response.status();
+                    let rate_limited = status == StatusCode::TOO_MANY_REQUESTS;
+                    if !(can_retry && (rate_limited || (retryable && is_transient(status)))) {
+                        break Ok(response);
+                    }
+                    self.retry.delay(attempt, response.headers())
+                }
+                Err(err) => {
+                    if !(can_retry && retryable && is_transient_error(&err)) {
+                        break Err(err).context(format!("{method} request failed"));
+                    }
+                    self.retry.backoff(attempt)
+                }
+            };
+
+            attempt += 1;
+            if let Some(pb) = &spinner {
+                pb.set_message(format!(
+                    "Retrying in {:.1}s ({attempt}/{})...",
+                    delay.as_secs_f64(),
+                    self.retry.max_retries
+                ));
+            }
+            tokio::time::sleep(delay).await;
+            if let Some(pb) = &spinner {
+                pb.set_message(message.to_string());
+            }
+        };
+
         if let Some(pb) = spinner {
             pb.finish_and_clear();
         }
@@ -98,25 +509,51 @@ impl Client {
     }
 }
 
+/// Redirect policy for API requests: follow redirects within the same host
+/// only, so the API key header is never forwarded elsewhere.
+fn same_host_redirects(attempt: reqwest::redirect::Attempt) -> reqwest::redirect::Action {
+    let same_host = attempt.previous().last().is_some_and(|prev| {
+        prev.host_str() == attempt.url().host_str()
+            && prev.port_or_known_default() == attempt.url().port_or_known_default()
+    });
+    if !same_host {
+        attempt.stop()
+    } else if attempt.previous().len() > 10 {
+        attempt.error("too many redirects")
+    } else {
+        attempt.follow()
+    }
+}
+
 async fn handle_response(response: reqwest::Response) -> Result<serde_json::Value> {
     let status = response.status();
 
     if status.is_success() {
-        let body = response
-            .json::<serde_json::Value>()
+        let text = response
+            .text()
             .await
-            .context("failed to parse response JSON")?;
+            .context("failed to read response body")?;
+        if status == reqwest::StatusCode::NO_CONTENT || text.trim().is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+        let body = serde_json::from_str(&text).context("failed to parse response JSON")?;
         return Ok(body);
     }
 
+    Err(response_error(response).await)
+}
+
+/// Maps an unsuccessful response to an error describing it.
+async fn response_error(response: reqwest::Response) -> anyhow::Error {
+    let status = response.status();
     let body_text = response.text().await.unwrap_or_default();
 
     match status.as_u16() {
-        401 => bail!("unauthorized — check your API key"),
-        404 => bail!("not found: {body_text}"),
-        429 => bail!("rate limited — try again later"),
-        500..=599 => bail!("server error ({status}): {body_text}"),
-        _ => bail!("request failed ({status}): {body_text}"),
+        401 => anyhow!("unauthorized — check your API key"),
+        404 => anyhow!("not found: {body_text}"),
+        429 => anyhow!("rate limited — try again later"),
+        500..=599 => anyhow!("server error ({status}): {body_text}"),
+        _ => anyhow!("request failed ({status}): {body_text}"),
     }
 }
 
@@ -133,6 +570,17 @@ mod tests {
             base_url: base_url.to_string(),
             workspace: Some("test-ws".to_string()),
             timeout: 5,
+            max_retries: 0,
+            ..Settings::default()
+        }
+    }
+
+    fn retry_settings(base_url: &str) -> Settings {
+        Settings {
+            max_retries: 2,
+            retry_backoff_ms: 1,
+            retry_jitter: false,
+            ..test_settings(base_url)
         }
     }
 
@@ -145,6 +593,7 @@ mod tests {
             base_url: "https://example.com".to_string(),
             workspace: None,
             timeout: 30,
+            ..Settings::default()
         };
         let err = Client::new(&settings, true).unwrap_err();
         let msg = format!("{err:#}");
@@ -216,6 +665,297 @@ mod tests {
         assert_eq!(result["id"], "123");
     }
 
+    #[tokio::test]
+    async fn test_patch_sends_json_body() {
+        let mock_server = MockServer::start().await;
+        let body = serde_json::json!({"name": "Renamed"});
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/issues/123"))
+            .and(body_json(&body))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"id": "123", "name": "Renamed"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&test_settings(&mock_server.uri()), true).unwrap();
+        let result = client.patch("issues/123", &body).await.unwrap();
+        assert_eq!(result["name"], "Renamed");
+    }
+
+    #[tokio::test]
+    async fn test_delete_handles_no_content() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/issues/123"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&test_settings(&mock_server.uri()), true).unwrap();
+        let result = client.delete("issues/123").await.unwrap();
+        assert!(result.is_null());
+    }
+
+    #[tokio::test]
+    async fn test_post_handles_empty_body() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/issues/123/archive"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&test_settings(&mock_server.uri()), true).unwrap();
+        let result = client
+            .post("issues/123/archive", &serde_json::json!({}))
+            .await
+            .unwrap();
+        assert!(result.is_null());
+    }
+
+    // ── Pagination ──
+
+    #[tokio::test]
+    async fn test_paginate_follows_next_cursor() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/items"))
+            .and(query_param("cursor", "2:1:0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [{"id": 3}],
+                "next_cursor": "2:2:0",
+                "next_page_results": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [{"id": 1}, {"id": 2}],
+                "next_cursor": "2:1:0",
+                "next_page_results": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&test_settings(&mock_server.uri()), true).unwrap();
+        let mut pages = Vec::new();
+        let total = client
+            .paginate("items", &[("per_page", "2")], None, None, |items| {
+                pages.push(items.len());
+                Ok(())
+            })
+            .await
+            .unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(pages, vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_at_limit() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [{"id": 1}, {"id": 2}, {"id": 3}],
+                "next_cursor": "3:1:0",
+                "next_page_results": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&test_settings(&mock_server.uri()), true).unwrap();
+        let mut ids = Vec::new();
+        client
+            .paginate("items", &[], None, Some(2), |items| {
+                ids.extend(items.iter().map(|i| i["id"].clone()));
+                Ok(())
+            })
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![serde_json::json!(1), serde_json::json!(2)]);
+    }
+
+    // ── Retries ──
+
+    #[tokio::test]
+    async fn test_get_retries_transient_server_error() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"ok": true})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&retry_settings(&mock_server.uri()), true).unwrap();
+        let result = client.get("test").await.unwrap();
+        assert_eq!(result["ok"], true);
+    }
+
+    #[tokio::test]
+    async fn test_post_is_not_retried_on_server_error() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&retry_settings(&mock_server.uri()), true).unwrap();
+        let err = client
+            .post("test", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("server error"));
+    }
+
+    #[tokio::test]
+    async fn test_post_is_retried_when_rate_limited() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": "1"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&retry_settings(&mock_server.uri()), true).unwrap();
+        let result = client.post("test", &serde_json::json!({})).await.unwrap();
+        assert_eq!(result["id"], "1");
+    }
+
+    #[tokio::test]
+    async fn test_retries_give_up_after_max_attempts() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new(&retry_settings(&mock_server.uri()), true).unwrap();
+        let err = client.get("test").await.unwrap_err();
+        assert!(format!("{err:#}").contains("rate limited"));
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            jitter: false,
+            retry_non_idempotent: false,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_within_bounds() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retry_non_idempotent: false,
+        };
+        for _ in 0..20 {
+            let delay = policy.backoff(1);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_server_delay_prefers_retry_after() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", HeaderValue::from_static("7"));
+        headers.insert("X-RateLimit-Reset", HeaderValue::from_static("30"));
+        assert_eq!(server_delay(&headers), Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn test_delay_caps_server_hint() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+            jitter: false,
+            retry_non_idempotent: false,
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert("Retry-After", HeaderValue::from_static("3600"));
+        assert_eq!(policy.delay(0, &headers), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_server_delay_reads_reset_timestamp() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-RateLimit-Reset",
+            HeaderValue::from_str(&(now + 5).to_string()).unwrap(),
+        );
+        let delay = server_delay(&headers).unwrap();
+        assert!(delay <= Duration::from_secs(5) && delay >= Duration::from_secs(4));
+    }
+
+    // ── Rate limiting ──
+
+    #[tokio::test]
+    async fn test_rate_limit_remaining_throttles_next_request() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"ok": true}))
+                    .insert_header("X-RateLimit-Remaining", "0")
+                    .insert_header("X-RateLimit-Reset", "1"),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let settings = Settings {
+            rate_limit: 600,
+            ..test_settings(&mock_server.uri())
+        };
+        let client = Client::new(&settings, true).unwrap();
+        client.get("test").await.unwrap();
+        let started = Instant::now();
+        client.get("test").await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(900));
+    }
+
     // ── Error handling ──
 
     #[tokio::test]
@@ -277,4 +1017,87 @@ mod tests {
         let msg = format!("{err:#}");
         assert!(msg.contains("server error"), "got: {msg}");
     }
+
+    // ── Attachments ──
+
+    #[tokio::test]
+    async fn test_upload_posts_form_without_api_key() {
+        let storage = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/bucket"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&storage)
+            .await;
+
+        let client = Client::new(&test_settings("http://127.0.0.1:1"), true).unwrap();
+        let fields = serde_json::json!({ "key": "uploads/log.txt", "policy": "abc" });
+        client
+            .upload(
+                &format!("{}/bucket", storage.uri()),
+                fields.as_object().unwrap(),
+                "log.txt",
+                "text/plain",
+                b"hello".to_vec(),
+            )
+            .await
+            .unwrap();
+
+        let requests = storage.received_requests().await.unwrap();
+        assert!(requests[0].headers.get("X-API-Key").is_none());
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("uploads/log.txt"));
+        assert!(body.contains("filename=\"log.txt\""));
+        assert!(body.contains("hello"));
+    }
+
+    #[tokio::test]
+    async fn test_download_follows_redirect_to_storage_without_api_key() {
+        let api = MockServer::start().await;
+        let storage = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/files/a1/"))
+            .and(header("X-API-Key", "test-key"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("Location", format!("{}/signed/a1", storage.uri())),
+            )
+            .mount(&api)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/signed/a1"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"file contents".to_vec()))
+            .expect(1)
+            .mount(&storage)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("out.txt");
+        let client = Client::new(&test_settings(&api.uri()), true).unwrap();
+        let size = client.download("files/a1/", &dest).await.unwrap();
+
+        assert_eq!(size, 13);
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "file contents");
+        let requests = storage.received_requests().await.unwrap();
+        assert!(requests[0].headers.get("X-API-Key").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_download_maps_api_errors() {
+        let api = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/files/missing/"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("gone"))
+            .mount(&api)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = Client::new(&test_settings(&api.uri()), true).unwrap();
+        let err = client
+            .download("files/missing/", &dir.path().join("x"))
+            .await
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("not found: gone"));
+        assert!(!dir.path().join("x").exists());
+    }
 }
@@ -1,7 +1,14 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
+use clap::ValueEnum;
 use comfy_table::{Cell, Color, Table, presets::UTF8_BORDERS_ONLY};
+use std::collections::{HashMap, HashSet};
+use std::io::{IsTerminal, Read, Write};
+use std::path::Path;
 
 use crate::client::Client;
+use crate::duration;
+use crate::html;
+use crate::manifest::{self, Action, Kind, Manifest};
 
 fn header(name: &str) -> Cell {
     Cell::new(name).fg(Color::Cyan)
@@ -18,320 +25,596 @@ fn priority_cell(priority: &str) -> Cell {
     Cell::new(priority).fg(color)
 }
 
-pub async fn projects_list(client: &Client, workspace: &str, json_mode: bool) -> Result<()> {
-    let data = client
-        .get(&format!("workspaces/{workspace}/projects/"))
-        .await?;
+/// Formats an RFC 3339 timestamp relative to now ("5m ago"), falling back to
+/// the date for anything older than a month.
+fn relative_time(timestamp: &str) -> String {
+    let Ok(time) = chrono::DateTime::parse_from_rfc3339(timestamp) else {
+        return timestamp.to_string();
+    };
+    let secs = (chrono::Utc::now() - time.with_timezone(&chrono::Utc)).num_seconds();
+    match secs {
+        ..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", secs / 60),
+        3600..86400 => format!("{}h ago", secs / 3600),
+        86400..2_592_000 => format!("{}d ago", secs / 86400),
+        _ => time.format("%Y-%m-%d").to_string(),
+    }
+}
 
-    if json_mode {
-        println!("{}", serde_json::to_string_pretty(&data)?);
-    } else {
-        let results = data["results"]
-            .as_array()
-            .context("unexpected response format: missing 'results' array")?;
+/// Reads free-form text from `--<flag>`, a file (`-` for stdin), or piped stdin.
+pub fn read_text(text: Option<&str>, file: Option<&Path>, what: &str) -> Result<String> {
+    let content = match (text, file) {
+        (Some(t), _) => t.to_string(),
+        (None, Some(path)) if path == Path::new("-") => read_stdin()?,
+        (None, Some(path)) => std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?,
+        (None, None) if !std::io::stdin().is_terminal() => read_stdin()?,
+        (None, None) => bail!("{what} is required — pass it as a flag, via --file, or on stdin"),
+    };
+    if content.trim().is_empty() {
+        bail!("{what} is empty");
+    }
+    Ok(content)
+}
+
+fn read_stdin() -> Result<String> {
+    let mut buf = String::new();
+    std::io::stdin()
+        .read_to_string(&mut buf)
+        .context("failed to read stdin")?;
+    Ok(buf)
+}
+
+/// Asks the user to confirm a destructive action.
+/// Skipped when `yes` is set; refuses to proceed when stdin is not a TTY.
+fn confirm(prompt: &str, yes: bool) -> Result<()> {
+    if yes {
+        return Ok(());
+    }
+    if !std::io::stdin().is_terminal() {
+        bail!("confirmation required — pass --yes to proceed non-interactively");
+    }
+
+    let term = console::Term::stderr();
+    term.write_str(&format!("{prompt} [y/N] "))?;
+    let answer = term.read_line()?;
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Ok(()),
+        _ => bail!("aborted"),
+    }
+}
+
+/// Pagination options shared by the list commands.
+pub struct Paging<'a> {
+    pub per_page: Option<u32>,
+    pub cursor: Option<&'a str>,
+    /// Follow `next_cursor` until all results are fetched.
+    pub all: bool,
+    /// Stop after this many results (implies following `next_cursor`).
+    pub limit: Option<usize>,
+}
+
+/// Fetches a list endpoint that returns either a bare array or a page with a
+/// `results` array. Only the first page is read.
+async fn fetch_items(client: &Client, path: &str) -> Result<Vec<serde_json::Value>> {
+    let mut data = client.get(path).await?;
+    let items = match data.as_array_mut() {
+        Some(arr) => arr,
+        None => data["results"]
+            .as_array_mut()
+            .context("unexpected response format: missing 'results' array")?,
+    };
+    Ok(std::mem::take(items))
+}
+
+/// How a list command renders its items in table mode.
+struct ListView<'a> {
+    /// Plural noun used in the empty message, e.g. "projects".
+    noun: &'a str,
+    headers: Vec<Cell>,
+    row: fn(&serde_json::Value) -> Vec<Cell>,
+}
+
+/// Fetches a paginated list and prints it.
+///
+/// Without `--all`/`--limit` a single page is fetched and printed as-is, with
+/// a hint about the next cursor in table mode. Otherwise pages are followed
+/// automatically: table mode streams rows page by page, JSON mode prints one
+/// merged `results` array.
+async fn list_paged(
+    client: &Client,
+    path: &str,
+    mut query: Vec<(&str, &str)>,
+    paging: &Paging<'_>,
+    view: ListView<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let per_page = paging.per_page.map(|n| n.to_string());
+    if let Some(p) = &per_page {
+        query.push(("per_page", p));
+    }
+
+    if !paging.all && paging.limit.is_none() {
+        if let Some(c) = paging.cursor {
+            query.push(("cursor", c));
+        }
+        let data = client.get_with_params(path, &query).await?;
+
+        if json_mode {
+            println!("{}", serde_json::to_string_pretty(&data)?);
+            return Ok(());
+        }
+
+        let results = match data.as_array() {
+            Some(arr) => arr,
+            None => data["results"]
+                .as_array()
+                .context("unexpected response format: missing 'results' array")?,
+        };
 
         if results.is_empty() {
-            println!("No projects found.");
+            println!("No {} found.", view.noun);
             return Ok(());
         }
 
         let mut table = Table::new();
         table.load_preset(UTF8_BORDERS_ONLY);
-        table.set_header(vec![header("Name"), header("Identifier"), header("ID")]);
-        for project in results {
-            table.add_row(vec![
-                Cell::new(project["name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
-                Cell::new(project["identifier"].as_str().unwrap_or("")),
-                Cell::new(project["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
-            ]);
+        table.set_header(view.headers);
+        for item in results {
+            table.add_row((view.row)(item));
         }
         println!("{table}");
+
+        if data["next_page_results"].as_bool() == Some(true)
+            && let Some(next) = data["next_cursor"].as_str()
+        {
+            let dim = console::Style::new().dim();
+            println!(
+                "{}",
+                dim.apply_to(format!("More results: --cursor {next} (or --all)"))
+            );
+        }
+        return Ok(());
+    }
+
+    if json_mode {
+        let mut all = Vec::new();
+        client
+            .paginate(path, &query, paging.cursor, paging.limit, |items| {
+                all.extend_from_slice(items);
+                Ok(())
+            })
+            .await?;
+        let merged = serde_json::json!({ "count": all.len(), "results": all });
+        println!("{}", serde_json::to_string_pretty(&merged)?);
+        return Ok(());
+    }
+
+    let mut headers = Some(view.headers);
+    let total = client
+        .paginate(path, &query, paging.cursor, paging.limit, |items| {
+            if items.is_empty() {
+                return Ok(());
+            }
+            let mut table = Table::new();
+            table.load_preset(UTF8_BORDERS_ONLY);
+            if let Some(h) = headers.take() {
+                table.set_header(h);
+            }
+            for item in items {
+                table.add_row((view.row)(item));
+            }
+            println!("{table}");
+            Ok(())
+        })
+        .await?;
+
+    if total == 0 {
+        println!("No {} found.", view.noun);
     }
 
     Ok(())
 }
 
-pub async fn states_list(
+pub async fn projects_list(
     client: &Client,
     workspace: &str,
-    project: &str,
+    paging: &Paging<'_>,
     json_mode: bool,
 ) -> Result<()> {
-    let data = client
-        .get(&format!(
-            "workspaces/{workspace}/projects/{project}/states/"
-        ))
-        .await?;
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/"),
+        Vec::new(),
+        paging,
+        ListView {
+            noun: "projects",
+            headers: vec![header("Name"), header("Identifier"), header("ID")],
+            row: |project| {
+                vec![
+                    Cell::new(project["name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
+                    Cell::new(project["identifier"].as_str().unwrap_or("")),
+                    Cell::new(project["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
 
-    if json_mode {
-        println!("{}", serde_json::to_string_pretty(&data)?);
-    } else {
-        let results = data["results"]
-            .as_array()
-            .context("unexpected response format: missing 'results' array")?;
+/// Project settings shared by `projects create` and `projects update`.
+pub struct ProjectFields<'a> {
+    pub description: Option<&'a str>,
+    /// 0 = secret, 2 = public.
+    pub network: Option<u8>,
+    pub lead: Option<&'a str>,
+    pub default_assignee: Option<&'a str>,
+    pub cycles: Option<bool>,
+    pub modules: Option<bool>,
+    pub pages: Option<bool>,
+}
 
-        if results.is_empty() {
-            println!("No states found.");
-            return Ok(());
+impl ProjectFields<'_> {
+    fn insert_into(&self, obj: &mut serde_json::Map<String, serde_json::Value>) {
+        if let Some(d) = self.description {
+            obj.insert("description".to_string(), serde_json::json!(d));
         }
-
-        let mut table = Table::new();
-        table.load_preset(UTF8_BORDERS_ONLY);
-        table.set_header(vec![header("Name"), header("Group"), header("ID")]);
-        for state in results {
-            table.add_row(vec![
-                Cell::new(state["name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
-                Cell::new(state["group"].as_str().unwrap_or("")),
-                Cell::new(state["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
-            ]);
+        if let Some(n) = self.network {
+            obj.insert("network".to_string(), serde_json::json!(n));
+        }
+        if let Some(l) = self.lead {
+            obj.insert("project_lead".to_string(), serde_json::json!(l));
+        }
+        if let Some(a) = self.default_assignee {
+            obj.insert("default_assignee".to_string(), serde_json::json!(a));
+        }
+        for (key, value) in [
+            ("cycle_view", self.cycles),
+            ("module_view", self.modules),
+            ("page_view", self.pages),
+        ] {
+            if let Some(v) = value {
+                obj.insert(key.to_string(), serde_json::json!(v));
+            }
         }
-        println!("{table}");
     }
+}
 
-    Ok(())
+fn network_name(value: &serde_json::Value) -> &'static str {
+    match value.as_u64() {
+        Some(2) => "public",
+        Some(0) => "secret",
+        _ => "",
+    }
 }
 
-pub async fn labels_list(
+pub async fn projects_get(
     client: &Client,
     workspace: &str,
-    project: &str,
+    id: &str,
     json_mode: bool,
 ) -> Result<()> {
     let data = client
-        .get(&format!(
-            "workspaces/{workspace}/projects/{project}/labels/"
-        ))
+        .get(&format!("workspaces/{workspace}/projects/{id}/"))
         .await?;
 
     if json_mode {
         println!("{}", serde_json::to_string_pretty(&data)?);
-    } else {
-        let results = data["results"]
-            .as_array()
-            .context("unexpected response format: missing 'results' array")?;
+        return Ok(());
+    }
 
-        if results.is_empty() {
-            println!("No labels found.");
-            return Ok(());
-        }
+    let members: HashMap<String, String> = client
+        .fetch_all(&format!("workspaces/{workspace}/projects/{id}/members/"))
+        .await
+        .unwrap_or_default()
+        .iter()
+        .map(crate::resolve::project_member)
+        .filter_map(|m| {
+            Some((
+                m["id"].as_str()?.to_string(),
+                m["display_name"].as_str()?.to_string(),
+            ))
+        })
+        .collect();
+    let person = |value: &serde_json::Value| -> String {
+        let id = value.as_str().unwrap_or("");
+        members.get(id).cloned().unwrap_or_else(|| id.to_string())
+    };
 
-        let mut table = Table::new();
-        table.load_preset(UTF8_BORDERS_ONLY);
-        table.set_header(vec![header("Name"), header("ID")]);
-        for label in results {
-            table.add_row(vec![
-                Cell::new(label["name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
-                Cell::new(label["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
-            ]);
+    let cyan = console::Style::new().cyan();
+    let bold = console::Style::new().bold();
+    let dim = console::Style::new().dim();
+
+    println!(
+        "{} {}",
+        bold.apply_to(data["name"].as_str().unwrap_or("(unnamed)")),
+        dim.apply_to(format!("({})", data["identifier"].as_str().unwrap_or("")))
+    );
+    if let Some(d) = data["description"].as_str().filter(|d| !d.is_empty()) {
+        println!("  {} {d}", cyan.apply_to("description:"));
+    }
+    println!(
+        "  {} {}",
+        cyan.apply_to("network:    "),
+        network_name(&data["network"])
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("lead:       "),
+        person(&data["project_lead"])
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("assignee:   "),
+        person(&data["default_assignee"])
+    );
+    let features: Vec<&str> = [
+        ("cycle_view", "cycles"),
+        ("module_view", "modules"),
+        ("page_view", "pages"),
+        ("inbox_view", "intake"),
+    ]
+    .into_iter()
+    .filter(|(key, _)| data[*key].as_bool() == Some(true))
+    .map(|(_, name)| name)
+    .collect();
+    println!(
+        "  {} {}",
+        cyan.apply_to("features:   "),
+        if features.is_empty() {
+            "none".to_string()
+        } else {
+            features.join(", ")
         }
-        println!("{table}");
+    );
+    if let Some(archived) = data["archived_at"].as_str() {
+        println!(
+            "  {} {}",
+            cyan.apply_to("archived:   "),
+            archived.get(..10).unwrap_or(archived)
+        );
     }
+    println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or(id)));
 
     Ok(())
 }
 
-pub async fn members_list(
+pub struct ProjectsCreateParams<'a> {
+    pub name: &'a str,
+    pub identifier: &'a str,
+    pub fields: ProjectFields<'a>,
+}
+
+pub async fn projects_create(
     client: &Client,
     workspace: &str,
-    project: &str,
+    params: &ProjectsCreateParams<'_>,
     json_mode: bool,
 ) -> Result<()> {
+    let mut body = serde_json::json!({
+        "name": params.name,
+        "identifier": params.identifier.to_uppercase(),
+    });
+    params.fields.insert_into(body.as_object_mut().unwrap());
+
     let data = client
-        .get(&format!(
-            "workspaces/{workspace}/projects/{project}/members/"
-        ))
+        .post(&format!("workspaces/{workspace}/projects/"), &body)
         .await?;
 
     if json_mode {
         println!("{}", serde_json::to_string_pretty(&data)?);
     } else {
-        let results = match data.as_array() {
-            Some(arr) => arr,
-            None => data["results"]
-                .as_array()
-                .context("unexpected response format")?,
-        };
-
-        if results.is_empty() {
-            println!("No members found.");
-            return Ok(());
-        }
-
-        let mut table = Table::new();
-        table.load_preset(UTF8_BORDERS_ONLY);
-        table.set_header(vec![header("Name"), header("ID")]);
-        for member in results {
-            table.add_row(vec![
-                Cell::new(member["display_name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
-                Cell::new(member["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
-            ]);
-        }
-        println!("{table}");
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!(
+            "{} project {} ({})",
+            green.apply_to("Created"),
+            data["name"].as_str().unwrap_or(params.name),
+            data["identifier"].as_str().unwrap_or(params.identifier)
+        );
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
     }
 
     Ok(())
 }
 
-pub struct IssuesListParams<'a> {
-    pub project: &'a str,
-    pub state: Option<&'a str>,
-    pub assignee: Option<&'a str>,
-    pub per_page: u32,
-    pub cursor: Option<&'a str>,
+pub struct ProjectsUpdateParams<'a> {
+    pub id: &'a str,
+    pub name: Option<&'a str>,
+    pub identifier: Option<&'a str>,
+    pub fields: ProjectFields<'a>,
 }
 
-pub async fn issues_list(
+pub async fn projects_update(
     client: &Client,
     workspace: &str,
-    params: &IssuesListParams<'_>,
+    params: &ProjectsUpdateParams<'_>,
     json_mode: bool,
 ) -> Result<()> {
-    let per_page_str = params.per_page.to_string();
-    let mut query: Vec<(&str, &str)> = vec![("per_page", &per_page_str)];
-    if let Some(s) = params.state {
-        query.push(("state", s));
+    let mut body = serde_json::json!({});
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(n) = params.name {
+        obj.insert("name".to_string(), serde_json::json!(n));
     }
-    if let Some(a) = params.assignee {
-        query.push(("assignee", a));
+    if let Some(i) = params.identifier {
+        obj.insert(
+            "identifier".to_string(),
+            serde_json::json!(i.to_uppercase()),
+        );
     }
-    if let Some(c) = params.cursor {
-        query.push(("cursor", c));
+    params.fields.insert_into(obj);
+
+    if obj.is_empty() {
+        bail!("nothing to update — pass at least one field to change");
     }
 
     let data = client
-        .get_with_params(
-            &format!("workspaces/{workspace}/projects/{}/issues/", params.project),
-            &query,
+        .patch(
+            &format!("workspaces/{workspace}/projects/{}/", params.id),
+            &body,
         )
         .await?;
 
     if json_mode {
         println!("{}", serde_json::to_string_pretty(&data)?);
     } else {
-        let results = data["results"]
-            .as_array()
-            .context("unexpected response format: missing 'results' array")?;
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} project {}",
+            green.apply_to("Updated"),
+            data["name"].as_str().unwrap_or(params.id)
+        );
+        print_changes(&body, &data);
+    }
 
-        if results.is_empty() {
-            println!("No issues found.");
-            return Ok(());
-        }
+    Ok(())
+}
 
-        let mut table = Table::new();
-        table.load_preset(UTF8_BORDERS_ONLY);
-        table.set_header(vec![
-            header("#"),
-            header("Name"),
-            header("Priority"),
-            header("ID"),
-        ]);
-        for issue in results {
-            let prio = issue["priority"].as_str().unwrap_or("none");
-            table.add_row(vec![
-                Cell::new(issue["sequence_id"].to_string()).fg(Color::White),
-                Cell::new(issue["name"].as_str().unwrap_or("(unnamed)")),
-                priority_cell(prio),
-                Cell::new(issue["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
-            ]);
-        }
-        println!("{table}");
+pub async fn projects_archive(
+    client: &Client,
+    workspace: &str,
+    id: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(&format!("Archive project {id}?"), yes)?;
+
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{id}/archive/"),
+            &serde_json::json!({}),
+        )
+        .await?;
+
+    if json_mode {
+        let out = if data.is_null() {
+            serde_json::json!({ "id": id, "archived": true })
+        } else {
+            data
+        };
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let yellow = console::Style::new().yellow().bold();
+        println!("{} project {id}", yellow.apply_to("Archived"));
     }
 
     Ok(())
 }
 
-pub async fn issues_get(
+pub async fn projects_unarchive(
     client: &Client,
     workspace: &str,
-    project: &str,
     id: &str,
     json_mode: bool,
 ) -> Result<()> {
-    let data = client
-        .get(&format!(
-            "workspaces/{workspace}/projects/{project}/issues/{id}/"
-        ))
+    client
+        .delete(&format!("workspaces/{workspace}/projects/{id}/archive/"))
         .await?;
 
     if json_mode {
-        println!("{}", serde_json::to_string_pretty(&data)?);
+        let out = serde_json::json!({ "id": id, "archived": false });
+        println!("{}", serde_json::to_string_pretty(&out)?);
     } else {
-        let cyan = console::Style::new().cyan();
-        let bold = console::Style::new().bold();
-        let dim = console::Style::new().dim();
+        let green = console::Style::new().green().bold();
+        println!("{} project {id}", green.apply_to("Unarchived"));
+    }
 
-        let name = data["name"].as_str().unwrap_or("(unnamed)");
-        let seq = &data["sequence_id"];
-        let priority = data["priority"].as_str().unwrap_or("none");
-        let state_id = data["state"].as_str().unwrap_or("");
-        let created = data["created_at"].as_str().unwrap_or("");
+    Ok(())
+}
 
-        println!("{} {}", bold.apply_to(seq), bold.apply_to(name));
-        println!("  {} {priority}", cyan.apply_to("priority:"));
-        println!("  {} {state_id}", cyan.apply_to("state:   "));
-        println!("  {} {created}", cyan.apply_to("created: "));
+pub async fn states_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/states/"),
+        Vec::new(),
+        paging,
+        ListView {
+            noun: "states",
+            headers: vec![header("Name"), header("Group"), header("ID")],
+            row: |state| {
+                vec![
+                    Cell::new(state["name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
+                    Cell::new(state["group"].as_str().unwrap_or("")),
+                    Cell::new(state["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
 
-        if let Some(assignees) = data["assignees"].as_array() {
-            let ids: Vec<&str> = assignees.iter().filter_map(|a| a.as_str()).collect();
-            if !ids.is_empty() {
-                println!("  {} {}", cyan.apply_to("assignees:"), ids.join(", "));
-            }
-        }
+/// Parses a `#RRGGBB` hex color (the `#` is optional), as used by states and labels.
+pub fn parse_color(value: &str) -> Result<String> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("invalid color '{value}' — use a hex color like #3a86ff");
+    }
+    Ok(format!("#{}", hex.to_lowercase()))
+}
 
-        if let Some(labels) = data["labels"].as_array() {
-            let ids: Vec<&str> = labels.iter().filter_map(|l| l.as_str()).collect();
-            if !ids.is_empty() {
-                println!("  {} {}", cyan.apply_to("labels:  "), ids.join(", "));
-            }
-        }
+/// Plane's state groups, which fix where a state sits in the workflow.
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum StateGroup {
+    Backlog,
+    Unstarted,
+    Started,
+    Completed,
+    Cancelled,
+}
 
-        let desc = data["description_html"].as_str().unwrap_or("");
-        if !desc.is_empty() {
-            println!("\n  {}", dim.apply_to(desc));
+impl StateGroup {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Backlog => "backlog",
+            Self::Unstarted => "unstarted",
+            Self::Started => "started",
+            Self::Completed => "completed",
+            Self::Cancelled => "cancelled",
         }
     }
-
-    Ok(())
 }
 
-pub struct IssuesCreateParams<'a> {
+pub struct StatesCreateParams<'a> {
     pub project: &'a str,
-    pub title: &'a str,
+    pub name: &'a str,
+    pub color: &'a str,
+    pub group: &'a str,
+    pub sequence: Option<f64>,
     pub description: Option<&'a str>,
-    pub state: Option<&'a str>,
-    pub priority: Option<&'a str>,
-    pub assignees: &'a [String],
-    pub labels: &'a [String],
 }
 
-pub async fn issues_create(
+pub async fn states_create(
     client: &Client,
     workspace: &str,
-    params: &IssuesCreateParams<'_>,
+    params: &StatesCreateParams<'_>,
     json_mode: bool,
 ) -> Result<()> {
-    let mut body = serde_json::json!({ "name": params.title });
+    let mut body = serde_json::json!({
+        "name": params.name,
+        "color": params.color,
+        "group": params.group,
+    });
     let obj = body.as_object_mut().unwrap();
 
-    if let Some(desc) = params.description {
-        obj.insert("description_html".to_string(), serde_json::json!(desc));
-    }
-    if let Some(s) = params.state {
-        obj.insert("state".to_string(), serde_json::json!(s));
-    }
-    if let Some(p) = params.priority {
-        obj.insert("priority".to_string(), serde_json::json!(p));
-    }
-    if !params.assignees.is_empty() {
-        obj.insert("assignees".to_string(), serde_json::json!(params.assignees));
+    if let Some(s) = params.sequence {
+        obj.insert("sequence".to_string(), serde_json::json!(s));
     }
-    if !params.labels.is_empty() {
-        obj.insert("labels".to_string(), serde_json::json!(params.labels));
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
     }
 
     let data = client
         .post(
-            &format!("workspaces/{workspace}/projects/{}/issues/", params.project),
+            &format!("workspaces/{workspace}/projects/{}/states/", params.project),
             &body,
         )
         .await?;
@@ -339,13 +622,3757 @@ pub async fn issues_create(
     if json_mode {
         println!("{}", serde_json::to_string_pretty(&data)?);
     } else {
-        let id = data["id"].as_str().unwrap_or("");
-        let seq = &data["sequence_id"];
-        let name = data["name"].as_str().unwrap_or("");
         let green = console::Style::new().green().bold();
         let dim = console::Style::new().dim();
-        println!("{} #{} {}", green.apply_to("Created"), seq, name);
-        println!("  {}", dim.apply_to(id));
+        println!(
+            "{} state {} ({})",
+            green.apply_to("Created"),
+            data["name"].as_str().unwrap_or(params.name),
+            params.group
+        );
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
+    }
+
+    Ok(())
+}
+
+pub struct StatesUpdateParams<'a> {
+    pub project: &'a str,
+    pub id: &'a str,
+    pub name: Option<&'a str>,
+    pub color: Option<&'a str>,
+    pub group: Option<&'a str>,
+    pub sequence: Option<f64>,
+    pub description: Option<&'a str>,
+}
+
+pub async fn states_update(
+    client: &Client,
+    workspace: &str,
+    params: &StatesUpdateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({});
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(n) = params.name {
+        obj.insert("name".to_string(), serde_json::json!(n));
+    }
+    if let Some(c) = params.color {
+        obj.insert("color".to_string(), serde_json::json!(c));
+    }
+    if let Some(g) = params.group {
+        obj.insert("group".to_string(), serde_json::json!(g));
+    }
+    if let Some(s) = params.sequence {
+        obj.insert("sequence".to_string(), serde_json::json!(s));
+    }
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
+    }
+
+    if obj.is_empty() {
+        bail!("nothing to update — pass at least one field to change");
+    }
+
+    let data = client
+        .patch(
+            &format!(
+                "workspaces/{workspace}/projects/{}/states/{}/",
+                params.project, params.id
+            ),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} state {}",
+            green.apply_to("Updated"),
+            data["name"].as_str().unwrap_or(params.id)
+        );
+        print_changes(&body, &data);
+    }
+
+    Ok(())
+}
+
+/// Lists the IDs of the issues in a state. `base` is the project's API path.
+async fn state_issues(client: &Client, base: &str, state: &str) -> Result<Vec<String>> {
+    let mut issues = Vec::new();
+    client
+        .paginate(
+            &format!("{base}/issues/"),
+            &[("state", state), ("per_page", "100")],
+            None,
+            None,
+            |items| {
+                issues.extend(
+                    items
+                        .iter()
+                        .filter(|i| i["state"].as_str() == Some(state))
+                        .filter_map(|i| i["id"].as_str().map(str::to_string)),
+                );
+                Ok(())
+            },
+        )
+        .await?;
+    Ok(issues)
+}
+
+/// Deletes a state. Plane refuses to delete a state that still has issues,
+/// so with `move_to` those issues are moved to another state first;
+/// without it the command fails before touching anything.
+pub async fn states_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    move_to: Option<&str>,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    if move_to == Some(id) {
+        bail!("--move-to must be a different state than the one being deleted");
+    }
+
+    let base = format!("workspaces/{workspace}/projects/{project}");
+    let issues = state_issues(client, &base, id).await?;
+
+    if !issues.is_empty() && move_to.is_none() {
+        bail!(
+            "state {id} still has {} issue(s) — pass --move-to <STATE> to move them first",
+            issues.len()
+        );
+    }
+    let prompt = if issues.is_empty() {
+        format!("Delete state {id}?")
+    } else {
+        format!("Move {} issue(s) and delete state {id}?", issues.len())
+    };
+    confirm(&prompt, yes)?;
+
+    if let Some(target) = move_to {
+        for issue in &issues {
+            client
+                .patch(
+                    &format!("{base}/issues/{issue}/"),
+                    &serde_json::json!({ "state": target }),
+                )
+                .await?;
+        }
+    }
+
+    client.delete(&format!("{base}/states/{id}/")).await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "deleted": true, "moved_issues": issues.len() });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        if !issues.is_empty() {
+            let dim = console::Style::new().dim();
+            println!(
+                "{}",
+                dim.apply_to(format!("Moved {} issue(s)", issues.len()))
+            );
+        }
+        let red = console::Style::new().red().bold();
+        println!("{} state {id}", red.apply_to("Deleted"));
+    }
+
+    Ok(())
+}
+
+/// Converts a `#rrggbb` label color to a terminal color.
+fn hex_color(value: &serde_json::Value) -> Option<Color> {
+    let hex = value.as_str()?.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+    Some(Color::Rgb {
+        r: channel(0)?,
+        g: channel(2)?,
+        b: channel(4)?,
+    })
+}
+
+/// Adds `label` and, indented below it, its child labels to `table`.
+fn add_label_rows(
+    table: &mut Table,
+    label: &serde_json::Value,
+    children: &HashMap<&str, Vec<&serde_json::Value>>,
+    prefix: &str,
+    connector: &str,
+    seen: &mut HashSet<String>,
+) {
+    let name = label["name"].as_str().unwrap_or("(unnamed)");
+    let swatch = match hex_color(&label["color"]) {
+        Some(color) => Cell::new("●").fg(color),
+        None => Cell::new(""),
+    };
+    table.add_row(vec![
+        Cell::new(format!("{prefix}{connector}{name}")).fg(Color::White),
+        swatch,
+        Cell::new(label["color"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+        Cell::new(label["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+    ]);
+
+    let id = label["id"].as_str().unwrap_or_default();
+    if !seen.insert(id.to_string()) {
+        return;
+    }
+    let kids = children.get(id).map_or(&[][..], Vec::as_slice);
+    let prefix = match connector {
+        "├─ " => format!("{prefix}│  "),
+        "└─ " => format!("{prefix}   "),
+        _ => prefix.to_string(),
+    };
+    for (i, child) in kids.iter().enumerate() {
+        let connector = if i + 1 == kids.len() {
+            "└─ "
+        } else {
+            "├─ "
+        };
+        add_label_rows(table, child, children, &prefix, connector, seen);
+    }
+}
+
+/// Lists every label in the project, with label groups rendered as a tree:
+/// child labels are indented under their parent.
+pub async fn labels_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let labels = client
+        .fetch_all(&format!(
+            "workspaces/{workspace}/projects/{project}/labels/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "count": labels.len(), "results": labels });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+    if labels.is_empty() {
+        println!("No labels found.");
+        return Ok(());
+    }
+
+    let by_name = |a: &&serde_json::Value, b: &&serde_json::Value| {
+        let name = |l: &serde_json::Value| l["name"].as_str().unwrap_or("").to_lowercase();
+        name(a).cmp(&name(b))
+    };
+    let ids: HashSet<&str> = labels.iter().filter_map(|l| l["id"].as_str()).collect();
+    let mut roots = Vec::new();
+    let mut children: HashMap<&str, Vec<&serde_json::Value>> = HashMap::new();
+    for label in &labels {
+        match label["parent"].as_str().filter(|p| ids.contains(p)) {
+            Some(parent) => children.entry(parent).or_default().push(label),
+            None => roots.push(label),
+        }
+    }
+    roots.sort_by(by_name);
+    for list in children.values_mut() {
+        list.sort_by(by_name);
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    table.set_header(vec![
+        header("Name"),
+        header(""),
+        header("Color"),
+        header("ID"),
+    ]);
+    let mut seen = HashSet::new();
+    for root in roots {
+        add_label_rows(&mut table, root, &children, "", "", &mut seen);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+pub struct LabelsCreateParams<'a> {
+    pub project: &'a str,
+    pub name: &'a str,
+    pub color: Option<&'a str>,
+    pub parent: Option<&'a str>,
+    pub description: Option<&'a str>,
+}
+
+pub async fn labels_create(
+    client: &Client,
+    workspace: &str,
+    params: &LabelsCreateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({ "name": params.name });
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(c) = params.color {
+        obj.insert("color".to_string(), serde_json::json!(c));
+    }
+    if let Some(p) = params.parent {
+        obj.insert("parent".to_string(), serde_json::json!(p));
+    }
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
+    }
+
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{}/labels/", params.project),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!(
+            "{} label {}",
+            green.apply_to("Created"),
+            data["name"].as_str().unwrap_or(params.name)
+        );
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
+    }
+
+    Ok(())
+}
+
+pub struct LabelsUpdateParams<'a> {
+    pub project: &'a str,
+    pub id: &'a str,
+    pub name: Option<&'a str>,
+    pub color: Option<&'a str>,
+    /// `Some(None)` moves the label to the top level.
+    pub parent: Option<Option<&'a str>>,
+    pub description: Option<&'a str>,
+}
+
+pub async fn labels_update(
+    client: &Client,
+    workspace: &str,
+    params: &LabelsUpdateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    if params.parent == Some(Some(params.id)) {
+        bail!("a label can't be its own parent");
+    }
+
+    let mut body = serde_json::json!({});
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(n) = params.name {
+        obj.insert("name".to_string(), serde_json::json!(n));
+    }
+    if let Some(c) = params.color {
+        obj.insert("color".to_string(), serde_json::json!(c));
+    }
+    if let Some(p) = params.parent {
+        obj.insert("parent".to_string(), serde_json::json!(p));
+    }
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
+    }
+
+    if obj.is_empty() {
+        bail!("nothing to update — pass at least one field to change");
+    }
+
+    let data = client
+        .patch(
+            &format!(
+                "workspaces/{workspace}/projects/{}/labels/{}/",
+                params.project, params.id
+            ),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} label {}",
+            green.apply_to("Updated"),
+            data["name"].as_str().unwrap_or(params.id)
+        );
+        print_changes(&body, &data);
+    }
+
+    Ok(())
+}
+
+pub async fn labels_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(&format!("Delete label {id}?"), yes)?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/labels/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} label {id}", red.apply_to("Deleted"));
+    }
+
+    Ok(())
+}
+
+pub async fn members_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/members/"),
+        Vec::new(),
+        paging,
+        members_view(),
+        json_mode,
+    )
+    .await
+}
+
+pub async fn workspace_members_list(
+    client: &Client,
+    workspace: &str,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/members/"),
+        Vec::new(),
+        paging,
+        members_view(),
+        json_mode,
+    )
+    .await
+}
+
+/// Plane's membership roles, shared by workspaces and projects.
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Role {
+    Admin,
+    Member,
+    Guest,
+}
+
+impl Role {
+    /// Plane's numeric role code.
+    pub fn code(&self) -> u64 {
+        match self {
+            Self::Admin => 20,
+            Self::Member => 15,
+            Self::Guest => 5,
+        }
+    }
+}
+
+/// Names a role code as the CLI spells it, e.g. `20` as `admin`.
+pub fn role_name(value: &serde_json::Value) -> String {
+    match value.as_u64() {
+        Some(code) => Role::value_variants()
+            .iter()
+            .find(|r| r.code() == code)
+            .and_then(ValueEnum::to_possible_value)
+            .map_or_else(|| code.to_string(), |v| v.get_name().to_string()),
+        None => value.as_str().unwrap_or("").to_string(),
+    }
+}
+
+fn role_cell(value: &serde_json::Value) -> Cell {
+    let role = role_name(value);
+    let color = match role.as_str() {
+        "admin" => Color::Yellow,
+        "guest" => Color::DarkGrey,
+        _ => Color::Reset,
+    };
+    Cell::new(role).fg(color)
+}
+
+/// Table view for member lists. Entries may be users or memberships, and are
+/// flattened the same way member names are resolved.
+fn members_view() -> ListView<'static> {
+    ListView {
+        noun: "members",
+        headers: vec![
+            header("Name"),
+            header("Email"),
+            header("Role"),
+            header("ID"),
+        ],
+        row: |entry| {
+            let member = crate::resolve::project_member(entry);
+            vec![
+                Cell::new(member["display_name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
+                Cell::new(member["email"].as_str().unwrap_or("")),
+                role_cell(&member["role"]),
+                Cell::new(member["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+            ]
+        },
+    }
+}
+
+pub async fn members_add(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    member: &str,
+    role: u64,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{project}/members/"),
+            &serde_json::json!({ "member": member, "role": role }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} {member} as {}",
+            green.apply_to("Added"),
+            role_name(&serde_json::json!(role))
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn members_set_role(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    member: &str,
+    role: u64,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .patch(
+            &format!("workspaces/{workspace}/projects/{project}/members/{member}/"),
+            &serde_json::json!({ "role": role }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} {member} is now {}",
+            green.apply_to("Updated"),
+            role_name(&serde_json::json!(role))
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn members_remove(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    member: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(&format!("Remove {member} from the project?"), yes)?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/members/{member}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": member, "removed": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} {member}", red.apply_to("Removed"));
+    }
+
+    Ok(())
+}
+
+pub struct IssuesListParams<'a> {
+    pub project: &'a str,
+    pub state: Option<&'a str>,
+    pub assignee: Option<&'a str>,
+    pub paging: Paging<'a>,
+}
+
+pub async fn issues_list(
+    client: &Client,
+    workspace: &str,
+    params: &IssuesListParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut query: Vec<(&str, &str)> = Vec::new();
+    if let Some(s) = params.state {
+        query.push(("state", s));
+    }
+    if let Some(a) = params.assignee {
+        query.push(("assignee", a));
+    }
+
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{}/issues/", params.project),
+        query,
+        &params.paging,
+        ListView {
+            noun: "issues",
+            headers: vec![
+                header("#"),
+                header("Name"),
+                header("Priority"),
+                header("ID"),
+            ],
+            row: |issue| {
+                let prio = issue["priority"].as_str().unwrap_or("none");
+                vec![
+                    Cell::new(issue["sequence_id"].to_string()).fg(Color::White),
+                    Cell::new(issue["name"].as_str().unwrap_or("(unnamed)")),
+                    priority_cell(prio),
+                    Cell::new(issue["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
+
+/// How `issues get` prints the issue description.
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum DescriptionFormat {
+    /// Issue details with the description rendered for the terminal
+    Text,
+    /// Only the description, converted to Markdown
+    Markdown,
+    /// Only the description, as stored
+    Html,
+}
+
+/// Width to wrap rendered text at: the terminal's, or 80 when not a TTY.
+fn terminal_width() -> usize {
+    console::Term::stdout()
+        .size_checked()
+        .map_or(80, |(_, cols)| usize::from(cols))
+}
+
+pub async fn issues_get(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    format: DescriptionFormat,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .get(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{id}/"
+        ))
+        .await?;
+    let desc = data["description_html"].as_str().unwrap_or("");
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else if format == DescriptionFormat::Markdown {
+        println!("{}", html::to_markdown(desc));
+    } else if format == DescriptionFormat::Html {
+        println!("{desc}");
+    } else {
+        let cyan = console::Style::new().cyan();
+        let bold = console::Style::new().bold();
+        let dim = console::Style::new().dim();
+
+        let name = data["name"].as_str().unwrap_or("(unnamed)");
+        let seq = &data["sequence_id"];
+        let priority = data["priority"].as_str().unwrap_or("none");
+        let state_id = data["state"].as_str().unwrap_or("");
+        let created = data["created_at"].as_str().unwrap_or("");
+
+        println!("{} {}", bold.apply_to(seq), bold.apply_to(name));
+        println!("  {} {priority}", cyan.apply_to("priority:"));
+        println!("  {} {state_id}", cyan.apply_to("state:   "));
+        println!("  {} {created}", cyan.apply_to("created: "));
+
+        if let Some(assignees) = data["assignees"].as_array() {
+            let ids: Vec<&str> = assignees.iter().filter_map(|a| a.as_str()).collect();
+            if !ids.is_empty() {
+                println!("  {} {}", cyan.apply_to("assignees:"), ids.join(", "));
+            }
+        }
+
+        if let Some(labels) = data["labels"].as_array() {
+            let ids: Vec<&str> = labels.iter().filter_map(|l| l.as_str()).collect();
+            if !ids.is_empty() {
+                println!("  {} {}", cyan.apply_to("labels:  "), ids.join(", "));
+            }
+        }
+
+        if let Some(parent) = data["parent"].as_str() {
+            println!("  {} {parent}", cyan.apply_to("parent:  "));
+        }
+
+        // Sub-issues are supplementary; don't fail the whole view if they can't be listed.
+        let children = fetch_children(client, workspace, project, id)
+            .await
+            .unwrap_or_default();
+        if !children.is_empty() {
+            println!("  {}", cyan.apply_to("sub-issues:"));
+            for child in &children {
+                println!(
+                    "    #{} {}",
+                    child["sequence_id"],
+                    child["name"].as_str().unwrap_or("(unnamed)")
+                );
+            }
+        }
+
+        let relations = fetch_relations(client, workspace, project, id)
+            .await
+            .unwrap_or_default();
+        if !relations.is_empty() {
+            println!("  {}", cyan.apply_to("relations:"));
+            for (label, issue) in &relations {
+                println!("    {label:<12} {}", related_issue_label(issue));
+            }
+        }
+
+        let links = fetch_links(client, workspace, project, id)
+            .await
+            .unwrap_or_default();
+        if !links.is_empty() {
+            println!("  {}", cyan.apply_to("links:"));
+            for link in &links {
+                let url = link["url"].as_str().unwrap_or("");
+                match link["title"].as_str().filter(|t| !t.is_empty()) {
+                    Some(title) => println!("    {title} {}", dim.apply_to(url)),
+                    None => println!("    {url}"),
+                }
+            }
+        }
+
+        let text = html::render_terminal(desc, terminal_width().saturating_sub(2));
+        if !text.is_empty() {
+            println!();
+            for line in text.lines() {
+                println!("  {line}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub struct IssuesCreateParams<'a> {
+    pub project: &'a str,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub state: Option<&'a str>,
+    pub priority: Option<&'a str>,
+    pub assignees: &'a [String],
+    pub labels: &'a [String],
+    pub parent: Option<&'a str>,
+}
+
+pub async fn issues_create(
+    client: &Client,
+    workspace: &str,
+    params: &IssuesCreateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({ "name": params.title });
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(desc) = params.description {
+        obj.insert("description_html".to_string(), serde_json::json!(desc));
+    }
+    if let Some(s) = params.state {
+        obj.insert("state".to_string(), serde_json::json!(s));
+    }
+    if let Some(p) = params.priority {
+        obj.insert("priority".to_string(), serde_json::json!(p));
+    }
+    if !params.assignees.is_empty() {
+        obj.insert("assignees".to_string(), serde_json::json!(params.assignees));
+    }
+    if !params.labels.is_empty() {
+        obj.insert("labels".to_string(), serde_json::json!(params.labels));
+    }
+    if let Some(p) = params.parent {
+        obj.insert("parent".to_string(), serde_json::json!(p));
+    }
+
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{}/issues/", params.project),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let id = data["id"].as_str().unwrap_or("");
+        let seq = &data["sequence_id"];
+        let name = data["name"].as_str().unwrap_or("");
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!("{} #{} {}", green.apply_to("Created"), seq, name);
+        println!("  {}", dim.apply_to(id));
+    }
+
+    Ok(())
+}
+
+pub struct IssuesUpdateParams<'a> {
+    pub project: &'a str,
+    pub id: &'a str,
+    pub title: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub state: Option<&'a str>,
+    pub priority: Option<&'a str>,
+    pub assignees: &'a [String],
+    pub labels: &'a [String],
+    pub start_date: Option<&'a str>,
+    pub target_date: Option<&'a str>,
+    pub parent: Option<&'a str>,
+}
+
+pub async fn issues_update(
+    client: &Client,
+    workspace: &str,
+    params: &IssuesUpdateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({});
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(t) = params.title {
+        obj.insert("name".to_string(), serde_json::json!(t));
+    }
+    if let Some(desc) = params.description {
+        obj.insert("description_html".to_string(), serde_json::json!(desc));
+    }
+    if let Some(s) = params.state {
+        obj.insert("state".to_string(), serde_json::json!(s));
+    }
+    if let Some(p) = params.priority {
+        obj.insert("priority".to_string(), serde_json::json!(p));
+    }
+    if !params.assignees.is_empty() {
+        obj.insert("assignees".to_string(), serde_json::json!(params.assignees));
+    }
+    if !params.labels.is_empty() {
+        obj.insert("labels".to_string(), serde_json::json!(params.labels));
+    }
+    if let Some(d) = params.start_date {
+        obj.insert("start_date".to_string(), serde_json::json!(d));
+    }
+    if let Some(d) = params.target_date {
+        obj.insert("target_date".to_string(), serde_json::json!(d));
+    }
+    if let Some(p) = params.parent {
+        obj.insert("parent".to_string(), serde_json::json!(p));
+    }
+
+    if obj.is_empty() {
+        bail!("nothing to update — pass at least one field to change");
+    }
+
+    let data = client
+        .patch(
+            &format!(
+                "workspaces/{workspace}/projects/{}/issues/{}/",
+                params.project, params.id
+            ),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let seq = &data["sequence_id"];
+        let name = data["name"].as_str().unwrap_or("");
+        let green = console::Style::new().green().bold();
+        println!("{} #{} {}", green.apply_to("Updated"), seq, name);
+        print_changes(&body, &data);
+    }
+
+    Ok(())
+}
+
+/// Prints each field sent in an update, preferring the value the server returned.
+fn print_changes(body: &serde_json::Value, data: &serde_json::Value) {
+    let cyan = console::Style::new().cyan();
+    for (key, sent) in body.as_object().into_iter().flatten() {
+        let value = match &data[key] {
+            serde_json::Value::Null => sent,
+            v => v,
+        };
+        println!(
+            "  {} {}",
+            cyan.apply_to(format!("{key}:")),
+            display_value(value)
+        );
+    }
+}
+
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+pub async fn issues_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(&format!("Delete issue {id}? This cannot be undone."), yes)?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} {id}", red.apply_to("Deleted"));
+    }
+
+    Ok(())
+}
+
+pub async fn issues_archive(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(&format!("Archive issue {id}?"), yes)?;
+
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{project}/issues/{id}/archive/"),
+            &serde_json::json!({}),
+        )
+        .await?;
+
+    if json_mode {
+        let out = if data.is_null() {
+            serde_json::json!({ "id": id, "archived": true })
+        } else {
+            data
+        };
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let yellow = console::Style::new().yellow().bold();
+        println!("{} {id}", yellow.apply_to("Archived"));
+    }
+
+    Ok(())
+}
+
+pub async fn issues_unarchive(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    json_mode: bool,
+) -> Result<()> {
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{id}/archive/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "archived": false });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!("{} {id}", green.apply_to("Unarchived"));
+    }
+
+    Ok(())
+}
+
+pub async fn comments_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/issues/{issue}/comments/"),
+        Vec::new(),
+        paging,
+        ListView {
+            noun: "comments",
+            headers: vec![
+                header("Author"),
+                header("When"),
+                header("Comment"),
+                header("ID"),
+            ],
+            row: |comment| {
+                let author = comment["actor_detail"]["display_name"]
+                    .as_str()
+                    .or_else(|| comment["created_by"].as_str())
+                    .unwrap_or("");
+                let when = comment["created_at"]
+                    .as_str()
+                    .map(relative_time)
+                    .unwrap_or_default();
+                let text = html::to_plain_text(comment["comment_html"].as_str().unwrap_or(""));
+                vec![
+                    Cell::new(author).fg(Color::White),
+                    Cell::new(when),
+                    Cell::new(text),
+                    Cell::new(comment["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
+
+pub async fn comments_add(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    comment_html: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{project}/issues/{issue}/comments/"),
+            &serde_json::json!({ "comment_html": comment_html }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!("{} comment", green.apply_to("Added"));
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
+    }
+
+    Ok(())
+}
+
+pub async fn comments_edit(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    comment: &str,
+    comment_html: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .patch(
+            &format!(
+                "workspaces/{workspace}/projects/{project}/issues/{issue}/comments/{comment}/"
+            ),
+            &serde_json::json!({ "comment_html": comment_html }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!("{} comment {comment}", green.apply_to("Updated"));
+    }
+
+    Ok(())
+}
+
+pub async fn comments_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    comment: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(&format!("Delete comment {comment}?"), yes)?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{issue}/comments/{comment}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": comment, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} comment {comment}", red.apply_to("Deleted"));
+    }
+
+    Ok(())
+}
+
+/// State groups in workflow order, with the count field Plane reports for each.
+const STATE_GROUPS: [(&str, &str); 5] = [
+    ("backlog", "backlog_issues"),
+    ("unstarted", "unstarted_issues"),
+    ("started", "started_issues"),
+    ("completed", "completed_issues"),
+    ("cancelled", "cancelled_issues"),
+];
+
+/// Derives a cycle's status from its dates: `current`, `upcoming`,
+/// `completed`, or `draft` when it has no dates yet.
+fn cycle_status(cycle: &serde_json::Value) -> &'static str {
+    let date = |key: &str| {
+        cycle[key]
+            .as_str()
+            .and_then(|s| s.get(..10))
+            .and_then(|s| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+    };
+    let today = chrono::Local::now().date_naive();
+    match (date("start_date"), date("end_date")) {
+        (Some(start), _) if start > today => "upcoming",
+        (_, Some(end)) if end < today => "completed",
+        (Some(_), Some(_)) => "current",
+        _ => "draft",
+    }
+}
+
+fn cycle_status_cell(status: &str) -> Cell {
+    let color = match status {
+        "current" => Color::Green,
+        "upcoming" => Color::Blue,
+        "completed" => Color::DarkGrey,
+        _ => Color::Reset,
+    };
+    Cell::new(status).fg(color)
+}
+
+/// Summarises issue counts per state group, e.g. "4/10 done · 3 started".
+fn group_counts(item: &serde_json::Value) -> String {
+    let total = item["total_issues"].as_u64().unwrap_or(0);
+    let completed = item["completed_issues"].as_u64().unwrap_or(0);
+    let mut parts = vec![format!("{completed}/{total} done")];
+    for (group, key) in STATE_GROUPS {
+        let count = item[key].as_u64().unwrap_or(0);
+        if group != "completed" && count > 0 {
+            parts.push(format!("{count} {group}"));
+        }
+    }
+    parts.join(" · ")
+}
+
+/// Formats a date field, trimming any time component.
+fn date_cell(value: &serde_json::Value) -> String {
+    value
+        .as_str()
+        .map(|s| s.get(..10).unwrap_or(s).to_string())
+        .unwrap_or_default()
+}
+
+pub async fn cycles_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/cycles/"),
+        Vec::new(),
+        paging,
+        ListView {
+            noun: "cycles",
+            headers: vec![
+                header("Name"),
+                header("Status"),
+                header("Start"),
+                header("End"),
+                header("Issues"),
+                header("ID"),
+            ],
+            row: |cycle| {
+                vec![
+                    Cell::new(cycle["name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
+                    cycle_status_cell(cycle_status(cycle)),
+                    Cell::new(date_cell(&cycle["start_date"])),
+                    Cell::new(date_cell(&cycle["end_date"])),
+                    Cell::new(group_counts(cycle)),
+                    Cell::new(cycle["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
+
+pub async fn cycles_get(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .get(&format!(
+            "workspaces/{workspace}/projects/{project}/cycles/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+        return Ok(());
+    }
+
+    let cyan = console::Style::new().cyan();
+    let bold = console::Style::new().bold();
+    let dim = console::Style::new().dim();
+
+    println!(
+        "{} {}",
+        bold.apply_to(data["name"].as_str().unwrap_or("(unnamed)")),
+        dim.apply_to(format!("({})", cycle_status(&data)))
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("start:"),
+        date_cell(&data["start_date"])
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("end:  "),
+        date_cell(&data["end_date"])
+    );
+
+    let total = data["total_issues"].as_u64().unwrap_or(0);
+    println!("  {} {total}", cyan.apply_to("issues:"));
+    for (group, key) in STATE_GROUPS {
+        let count = data[key].as_u64().unwrap_or(0);
+        println!("    {:<10} {count}", format!("{group}:"));
+    }
+
+    let desc = data["description"].as_str().unwrap_or("");
+    if !desc.is_empty() {
+        println!("\n  {desc}");
+    }
+
+    Ok(())
+}
+
+pub struct CyclesCreateParams<'a> {
+    pub project: &'a str,
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub start_date: Option<&'a str>,
+    pub end_date: Option<&'a str>,
+}
+
+pub async fn cycles_create(
+    client: &Client,
+    workspace: &str,
+    params: &CyclesCreateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({ "name": params.name });
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
+    }
+    if let Some(d) = params.start_date {
+        obj.insert("start_date".to_string(), serde_json::json!(d));
+    }
+    if let Some(d) = params.end_date {
+        obj.insert("end_date".to_string(), serde_json::json!(d));
+    }
+
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{}/cycles/", params.project),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!(
+            "{} cycle {}",
+            green.apply_to("Created"),
+            data["name"].as_str().unwrap_or("")
+        );
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
+    }
+
+    Ok(())
+}
+
+pub struct CyclesUpdateParams<'a> {
+    pub project: &'a str,
+    pub id: &'a str,
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub start_date: Option<&'a str>,
+    pub end_date: Option<&'a str>,
+}
+
+pub async fn cycles_update(
+    client: &Client,
+    workspace: &str,
+    params: &CyclesUpdateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({});
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(n) = params.name {
+        obj.insert("name".to_string(), serde_json::json!(n));
+    }
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
+    }
+    if let Some(d) = params.start_date {
+        obj.insert("start_date".to_string(), serde_json::json!(d));
+    }
+    if let Some(d) = params.end_date {
+        obj.insert("end_date".to_string(), serde_json::json!(d));
+    }
+
+    if obj.is_empty() {
+        bail!("nothing to update — pass at least one field to change");
+    }
+
+    let data = client
+        .patch(
+            &format!(
+                "workspaces/{workspace}/projects/{}/cycles/{}/",
+                params.project, params.id
+            ),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} cycle {}",
+            green.apply_to("Updated"),
+            data["name"].as_str().unwrap_or(params.id)
+        );
+        print_changes(&body, &data);
+    }
+
+    Ok(())
+}
+
+pub async fn cycles_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(
+        &format!("Delete cycle {id}? Its issues are kept but removed from the cycle."),
+        yes,
+    )?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/cycles/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} cycle {id}", red.apply_to("Deleted"));
+    }
+
+    Ok(())
+}
+
+pub async fn cycles_add_issues(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    cycle: &str,
+    issues: &[String],
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{project}/cycles/{cycle}/cycle-issues/"),
+            &serde_json::json!({ "issues": issues }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let noun = if issues.len() == 1 { "issue" } else { "issues" };
+        println!(
+            "{} {} {noun} to cycle {cycle}",
+            green.apply_to("Added"),
+            issues.len()
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn cycles_remove_issue(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    cycle: &str,
+    issue: &str,
+    json_mode: bool,
+) -> Result<()> {
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/cycles/{cycle}/cycle-issues/{issue}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "cycle": cycle, "issue": issue, "removed": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let yellow = console::Style::new().yellow().bold();
+        println!(
+            "{} issue {issue} from cycle {cycle}",
+            yellow.apply_to("Removed")
+        );
+    }
+
+    Ok(())
+}
+
+fn module_status_cell(status: &str) -> Cell {
+    let color = match status {
+        "in-progress" => Color::Yellow,
+        "completed" => Color::Green,
+        "paused" => Color::Blue,
+        "cancelled" => Color::DarkGrey,
+        _ => Color::Reset,
+    };
+    Cell::new(status).fg(color)
+}
+
+/// A member's display name from a `*_detail` object, falling back to the raw ID.
+fn member_name<'a>(detail: &'a serde_json::Value, id: &'a serde_json::Value) -> &'a str {
+    detail["display_name"]
+        .as_str()
+        .or_else(|| id.as_str())
+        .unwrap_or("")
+}
+
+/// Display names of a module's members, or their IDs when details aren't expanded.
+fn module_members(module: &serde_json::Value) -> Vec<String> {
+    let details = module["members_detail"].as_array();
+    let ids = module["members"].as_array();
+    match (details, ids) {
+        (Some(details), _) if !details.is_empty() => details
+            .iter()
+            .map(|d| member_name(d, &d["id"]).to_string())
+            .collect(),
+        (_, Some(ids)) => ids
+            .iter()
+            .filter_map(|id| id.as_str().map(str::to_string))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub async fn modules_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/modules/"),
+        Vec::new(),
+        paging,
+        ListView {
+            noun: "modules",
+            headers: vec![
+                header("Name"),
+                header("Status"),
+                header("Lead"),
+                header("Members"),
+                header("Target"),
+                header("Issues"),
+                header("ID"),
+            ],
+            row: |module| {
+                vec![
+                    Cell::new(module["name"].as_str().unwrap_or("(unnamed)")).fg(Color::White),
+                    module_status_cell(module["status"].as_str().unwrap_or("")),
+                    Cell::new(member_name(&module["lead_detail"], &module["lead"])),
+                    Cell::new(module_members(module).join(", ")),
+                    Cell::new(date_cell(&module["target_date"])),
+                    Cell::new(group_counts(module)),
+                    Cell::new(module["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
+
+pub async fn modules_get(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .get(&format!(
+            "workspaces/{workspace}/projects/{project}/modules/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+        return Ok(());
+    }
+
+    let cyan = console::Style::new().cyan();
+    let bold = console::Style::new().bold();
+    let dim = console::Style::new().dim();
+
+    println!(
+        "{} {}",
+        bold.apply_to(data["name"].as_str().unwrap_or("(unnamed)")),
+        dim.apply_to(format!(
+            "({})",
+            data["status"].as_str().unwrap_or("backlog")
+        ))
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("lead:   "),
+        member_name(&data["lead_detail"], &data["lead"])
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("members:"),
+        module_members(&data).join(", ")
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("start:  "),
+        date_cell(&data["start_date"])
+    );
+    println!(
+        "  {} {}",
+        cyan.apply_to("target: "),
+        date_cell(&data["target_date"])
+    );
+
+    let total = data["total_issues"].as_u64().unwrap_or(0);
+    println!("  {} {total}", cyan.apply_to("issues: "));
+    for (group, key) in STATE_GROUPS {
+        let count = data[key].as_u64().unwrap_or(0);
+        println!("    {:<10} {count}", format!("{group}:"));
+    }
+
+    let desc = data["description"].as_str().unwrap_or("");
+    if !desc.is_empty() {
+        println!("\n  {desc}");
+    }
+
+    Ok(())
+}
+
+/// Module statuses accepted by Plane.
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ModuleStatus {
+    Backlog,
+    Planned,
+    InProgress,
+    Paused,
+    Completed,
+    Cancelled,
+}
+
+impl ModuleStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Backlog => "backlog",
+            Self::Planned => "planned",
+            Self::InProgress => "in-progress",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+pub struct ModulesCreateParams<'a> {
+    pub project: &'a str,
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub status: Option<&'a str>,
+    pub lead: Option<&'a str>,
+    pub members: &'a [String],
+    pub start_date: Option<&'a str>,
+    pub target_date: Option<&'a str>,
+}
+
+pub async fn modules_create(
+    client: &Client,
+    workspace: &str,
+    params: &ModulesCreateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({ "name": params.name });
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
+    }
+    if let Some(s) = params.status {
+        obj.insert("status".to_string(), serde_json::json!(s));
+    }
+    if let Some(l) = params.lead {
+        obj.insert("lead".to_string(), serde_json::json!(l));
+    }
+    if !params.members.is_empty() {
+        obj.insert("members".to_string(), serde_json::json!(params.members));
+    }
+    if let Some(d) = params.start_date {
+        obj.insert("start_date".to_string(), serde_json::json!(d));
+    }
+    if let Some(d) = params.target_date {
+        obj.insert("target_date".to_string(), serde_json::json!(d));
+    }
+
+    let data = client
+        .post(
+            &format!(
+                "workspaces/{workspace}/projects/{}/modules/",
+                params.project
+            ),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!(
+            "{} module {}",
+            green.apply_to("Created"),
+            data["name"].as_str().unwrap_or("")
+        );
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
+    }
+
+    Ok(())
+}
+
+pub struct ModulesUpdateParams<'a> {
+    pub project: &'a str,
+    pub id: &'a str,
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub status: Option<&'a str>,
+    pub lead: Option<&'a str>,
+    pub members: &'a [String],
+    pub start_date: Option<&'a str>,
+    pub target_date: Option<&'a str>,
+}
+
+pub async fn modules_update(
+    client: &Client,
+    workspace: &str,
+    params: &ModulesUpdateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({});
+    let obj = body.as_object_mut().unwrap();
+
+    if let Some(n) = params.name {
+        obj.insert("name".to_string(), serde_json::json!(n));
+    }
+    if let Some(d) = params.description {
+        obj.insert("description".to_string(), serde_json::json!(d));
+    }
+    if let Some(s) = params.status {
+        obj.insert("status".to_string(), serde_json::json!(s));
+    }
+    if let Some(l) = params.lead {
+        obj.insert("lead".to_string(), serde_json::json!(l));
+    }
+    if !params.members.is_empty() {
+        obj.insert("members".to_string(), serde_json::json!(params.members));
+    }
+    if let Some(d) = params.start_date {
+        obj.insert("start_date".to_string(), serde_json::json!(d));
+    }
+    if let Some(d) = params.target_date {
+        obj.insert("target_date".to_string(), serde_json::json!(d));
+    }
+
+    if obj.is_empty() {
+        bail!("nothing to update — pass at least one field to change");
+    }
+
+    let data = client
+        .patch(
+            &format!(
+                "workspaces/{workspace}/projects/{}/modules/{}/",
+                params.project, params.id
+            ),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} module {}",
+            green.apply_to("Updated"),
+            data["name"].as_str().unwrap_or(params.id)
+        );
+        print_changes(&body, &data);
+    }
+
+    Ok(())
+}
+
+pub async fn modules_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(
+        &format!("Delete module {id}? Its issues are kept but removed from the module."),
+        yes,
+    )?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/modules/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} module {id}", red.apply_to("Deleted"));
+    }
+
+    Ok(())
+}
+
+pub async fn modules_add_issues(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    module: &str,
+    issues: &[String],
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{project}/modules/{module}/module-issues/"),
+            &serde_json::json!({ "issues": issues }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let noun = if issues.len() == 1 { "issue" } else { "issues" };
+        println!(
+            "{} {} {noun} to module {module}",
+            green.apply_to("Added"),
+            issues.len()
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn modules_remove_issue(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    module: &str,
+    issue: &str,
+    json_mode: bool,
+) -> Result<()> {
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/modules/{module}/module-issues/{issue}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "module": module, "issue": issue, "removed": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let yellow = console::Style::new().yellow().bold();
+        println!(
+            "{} issue {issue} from module {module}",
+            yellow.apply_to("Removed")
+        );
+    }
+
+    Ok(())
+}
+
+/// Maps state IDs to names for a project.
+async fn state_names(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+) -> Result<HashMap<String, String>> {
+    let states = client
+        .fetch_all(&format!(
+            "workspaces/{workspace}/projects/{project}/states/"
+        ))
+        .await?;
+    Ok(states
+        .iter()
+        .filter_map(|s| {
+            Some((
+                s["id"].as_str()?.to_string(),
+                s["name"].as_str()?.to_string(),
+            ))
+        })
+        .collect())
+}
+
+/// Fetches the direct sub-issues of an issue.
+async fn fetch_children(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+) -> Result<Vec<serde_json::Value>> {
+    let data = client
+        .get(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{id}/sub-issues/"
+        ))
+        .await?;
+    let children = data["sub_issues"]
+        .as_array()
+        .or_else(|| data["results"].as_array())
+        .or_else(|| data.as_array())
+        .context("unexpected response format: missing 'sub_issues' array")?;
+    Ok(children.clone())
+}
+
+pub async fn issues_children(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let children = fetch_children(client, workspace, project, id).await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "count": children.len(), "results": children });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+    if children.is_empty() {
+        println!("No sub-issues found.");
+        return Ok(());
+    }
+
+    let states = state_names(client, workspace, project).await?;
+    let mut table = Table::new();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    table.set_header(vec![
+        header("#"),
+        header("Name"),
+        header("State"),
+        header("Priority"),
+        header("ID"),
+    ]);
+    for issue in &children {
+        let state = issue["state"].as_str().unwrap_or("");
+        table.add_row(vec![
+            Cell::new(issue["sequence_id"].to_string()).fg(Color::White),
+            Cell::new(issue["name"].as_str().unwrap_or("(unnamed)")),
+            Cell::new(states.get(state).map_or(state, String::as_str)),
+            priority_cell(issue["priority"].as_str().unwrap_or("none")),
+            Cell::new(issue["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+        ]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+/// Issues of a project grouped by parent ID, each group ordered by sequence number.
+struct IssueTree<'a> {
+    children: HashMap<&'a str, Vec<&'a serde_json::Value>>,
+}
+
+impl<'a> IssueTree<'a> {
+    fn new(issues: &'a [serde_json::Value]) -> Self {
+        let mut children: HashMap<&str, Vec<&serde_json::Value>> = HashMap::new();
+        for issue in issues {
+            let parent = issue["parent"].as_str().unwrap_or("");
+            children.entry(parent).or_default().push(issue);
+        }
+        for list in children.values_mut() {
+            list.sort_by_key(|i| i["sequence_id"].as_u64().unwrap_or(0));
+        }
+        Self { children }
+    }
+
+    /// Top-level issues: those without a parent, or whose parent isn't in the project.
+    fn roots(&self, issues: &'a [serde_json::Value]) -> Vec<&'a serde_json::Value> {
+        let ids: HashSet<&str> = issues.iter().filter_map(|i| i["id"].as_str()).collect();
+        let mut roots: Vec<&serde_json::Value> = issues
+            .iter()
+            .filter(|i| i["parent"].as_str().is_none_or(|p| !ids.contains(p)))
+            .collect();
+        roots.sort_by_key(|i| i["sequence_id"].as_u64().unwrap_or(0));
+        roots
+    }
+
+    fn children_of(&self, issue: &serde_json::Value) -> &[&'a serde_json::Value] {
+        issue["id"]
+            .as_str()
+            .and_then(|id| self.children.get(id))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The issue with a nested `children` array, recursively.
+    fn to_json(&self, issue: &serde_json::Value, seen: &mut HashSet<String>) -> serde_json::Value {
+        let mut node = issue.clone();
+        let id = issue["id"].as_str().unwrap_or_default().to_string();
+        let children: Vec<serde_json::Value> = if seen.insert(id) {
+            self.children_of(issue)
+                .iter()
+                .map(|c| self.to_json(c, seen))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        if let Some(obj) = node.as_object_mut() {
+            obj.insert("children".to_string(), serde_json::json!(children));
+        }
+        node
+    }
+
+    fn print(
+        &self,
+        issue: &serde_json::Value,
+        states: &HashMap<String, String>,
+        prefix: &str,
+        connector: &str,
+        seen: &mut HashSet<String>,
+    ) {
+        let dim = console::Style::new().dim();
+        let state = issue["state"].as_str().unwrap_or("");
+        let priority = issue["priority"].as_str().unwrap_or("none");
+        println!(
+            "{}{} {}  {}",
+            dim.apply_to(format!("{prefix}{connector}")),
+            console::Style::new()
+                .bold()
+                .apply_to(format!("#{}", issue["sequence_id"])),
+            issue["name"].as_str().unwrap_or("(unnamed)"),
+            dim.apply_to(format!(
+                "{} · {priority}",
+                states.get(state).map_or(state, String::as_str)
+            )),
+        );
+
+        let id = issue["id"].as_str().unwrap_or_default().to_string();
+        if !seen.insert(id) {
+            return;
+        }
+        let child_prefix = match connector {
+            "├── " => format!("{prefix}│   "),
+            "└── " => format!("{prefix}    "),
+            _ => prefix.to_string(),
+        };
+        let children = self.children_of(issue);
+        for (i, child) in children.iter().enumerate() {
+            let connector = if i + 1 == children.len() {
+                "└── "
+            } else {
+                "├── "
+            };
+            self.print(child, states, &child_prefix, connector, seen);
+        }
+    }
+}
+
+pub async fn issues_tree(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    root: Option<&str>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut issues = Vec::new();
+    client
+        .paginate(
+            &format!("workspaces/{workspace}/projects/{project}/issues/"),
+            &[("per_page", "100")],
+            None,
+            None,
+            |items| {
+                issues.extend_from_slice(items);
+                Ok(())
+            },
+        )
+        .await?;
+
+    let tree = IssueTree::new(&issues);
+    let roots = match root {
+        Some(id) => vec![
+            issues
+                .iter()
+                .find(|i| i["id"].as_str() == Some(id))
+                .with_context(|| format!("issue {id} not found in project"))?,
+        ],
+        None => tree.roots(&issues),
+    };
+
+    let mut seen = HashSet::new();
+    if json_mode {
+        let nested: Vec<serde_json::Value> =
+            roots.iter().map(|r| tree.to_json(r, &mut seen)).collect();
+        println!("{}", serde_json::to_string_pretty(&nested)?);
+        return Ok(());
+    }
+    if roots.is_empty() {
+        println!("No issues found.");
+        return Ok(());
+    }
+
+    let states = state_names(client, workspace, project).await?;
+    for issue in roots {
+        tree.print(issue, &states, "", "", &mut seen);
+    }
+
+    Ok(())
+}
+
+/// Relation types as Plane names them, with the label the CLI uses.
+/// `blocking` is stored by Plane as `blocked_by` on the other issue.
+const RELATION_TYPES: [(&str, &str); 4] = [
+    ("blocking", "blocks"),
+    ("blocked_by", "blocked-by"),
+    ("duplicate", "duplicate-of"),
+    ("relates_to", "relates-to"),
+];
+
+/// Fetches an issue's relations as (label, related issue) pairs.
+async fn fetch_relations(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+) -> Result<Vec<(&'static str, serde_json::Value)>> {
+    let data = client
+        .get(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{id}/issue-relation/"
+        ))
+        .await?;
+    let mut relations = Vec::new();
+    for (key, label) in RELATION_TYPES {
+        for issue in data[key].as_array().into_iter().flatten() {
+            relations.push((label, issue.clone()));
+        }
+    }
+    Ok(relations)
+}
+
+/// Formats a related issue as `WEB-12 Name`, falling back to `#12` without an identifier.
+fn related_issue_label(issue: &serde_json::Value) -> String {
+    let name = issue["name"].as_str().unwrap_or("(unnamed)");
+    match issue["project_identifier"]
+        .as_str()
+        .or_else(|| issue["project__identifier"].as_str())
+    {
+        Some(ident) => format!("{ident}-{} {name}", issue["sequence_id"]),
+        None => format!("#{} {name}", issue["sequence_id"]),
+    }
+}
+
+pub async fn issues_relations(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    json_mode: bool,
+) -> Result<()> {
+    if json_mode {
+        let data = client
+            .get(&format!(
+                "workspaces/{workspace}/projects/{project}/issues/{id}/issue-relation/"
+            ))
+            .await?;
+        println!("{}", serde_json::to_string_pretty(&data)?);
+        return Ok(());
+    }
+
+    let relations = fetch_relations(client, workspace, project, id).await?;
+    if relations.is_empty() {
+        println!("No relations found.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    table.set_header(vec![header("Relation"), header("Issue"), header("ID")]);
+    for (label, issue) in &relations {
+        table.add_row(vec![
+            Cell::new(label).fg(Color::Yellow),
+            Cell::new(related_issue_label(issue)),
+            Cell::new(issue["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+        ]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+/// Adds relations from an issue, one request per relation type.
+/// `relations` pairs Plane's relation type with the related issue IDs.
+pub async fn issues_relate(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    relations: &[(&str, Vec<String>)],
+    json_mode: bool,
+) -> Result<()> {
+    if relations.iter().all(|(_, ids)| ids.is_empty()) {
+        bail!("no relations given — pass --blocks, --blocked-by, --duplicate-of or --relates-to");
+    }
+
+    let green = console::Style::new().green().bold();
+    let mut responses = Vec::new();
+    for (relation_type, issues) in relations.iter().filter(|(_, ids)| !ids.is_empty()) {
+        let data = client
+            .post(
+                &format!("workspaces/{workspace}/projects/{project}/issues/{id}/issue-relation/"),
+                &serde_json::json!({ "relation_type": relation_type, "issues": issues }),
+            )
+            .await?;
+        responses.push(data);
+        if !json_mode {
+            println!(
+                "{} {id} {} {}",
+                green.apply_to("Related"),
+                relation_label(relation_type),
+                issues.join(", ")
+            );
+        }
+    }
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&responses)?);
+    }
+
+    Ok(())
+}
+
+pub async fn issues_unrelate(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    id: &str,
+    relations: &[(&str, Vec<String>)],
+    json_mode: bool,
+) -> Result<()> {
+    if relations.iter().all(|(_, ids)| ids.is_empty()) {
+        bail!("no relations given — pass --blocks, --blocked-by, --duplicate-of or --relates-to");
+    }
+
+    let yellow = console::Style::new().yellow().bold();
+    let mut removed = Vec::new();
+    for (relation_type, issues) in relations {
+        for related in issues {
+            client
+                .post(
+                    &format!(
+                        "workspaces/{workspace}/projects/{project}/issues/{id}/remove-relation/"
+                    ),
+                    &serde_json::json!({ "relation_type": relation_type, "related_issue": related }),
+                )
+                .await?;
+            removed.push(serde_json::json!({
+                "relation_type": relation_type,
+                "related_issue": related,
+            }));
+            if !json_mode {
+                println!(
+                    "{} {id} {} {related}",
+                    yellow.apply_to("Unrelated"),
+                    relation_label(relation_type)
+                );
+            }
+        }
+    }
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "removed": removed });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    }
+
+    Ok(())
+}
+
+fn relation_label(relation_type: &str) -> &str {
+    RELATION_TYPES
+        .iter()
+        .find(|(key, _)| *key == relation_type)
+        .map_or(relation_type, |(_, label)| label)
+}
+
+pub async fn links_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/issues/{issue}/links/"),
+        Vec::new(),
+        paging,
+        ListView {
+            noun: "links",
+            headers: vec![header("Title"), header("URL"), header("ID")],
+            row: |link| {
+                vec![
+                    Cell::new(link["title"].as_str().unwrap_or("")).fg(Color::White),
+                    Cell::new(link["url"].as_str().unwrap_or("")).fg(Color::Blue),
+                    Cell::new(link["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
+
+/// Fetches every link attached to an issue.
+async fn fetch_links(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+) -> Result<Vec<serde_json::Value>> {
+    client
+        .fetch_all(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{issue}/links/"
+        ))
+        .await
+}
+
+pub async fn links_add(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    url: &str,
+    title: Option<&str>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut body = serde_json::json!({ "url": url });
+    if let Some(t) = title {
+        body["title"] = serde_json::json!(t);
+    }
+
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{project}/issues/{issue}/links/"),
+            &body,
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!("{} link {url}", green.apply_to("Added"));
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
+    }
+
+    Ok(())
+}
+
+/// Removes a link given by ID or by its URL.
+pub async fn links_remove(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    link: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let id = if link.starts_with("http://") || link.starts_with("https://") {
+        let links = fetch_links(client, workspace, project, issue).await?;
+        links
+            .iter()
+            .find(|l| l["url"].as_str() == Some(link))
+            .and_then(|l| l["id"].as_str())
+            .map(str::to_string)
+            .with_context(|| format!("no link with URL {link} on this issue"))?
+    } else {
+        link.to_string()
+    };
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{issue}/links/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} link {link}", red.apply_to("Removed"));
+    }
+
+    Ok(())
+}
+
+/// Fetches the attachments of an issue.
+async fn fetch_attachments(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+) -> Result<Vec<serde_json::Value>> {
+    fetch_items(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/issues/{issue}/issue-attachments/"),
+    )
+    .await
+}
+
+/// Reduces a server-supplied name to its last path component, so it can't
+/// point outside the directory it is joined to. Returns `None` when nothing
+/// usable is left.
+fn safe_file_name(name: &str) -> Option<&str> {
+    let file_name = Path::new(name).file_name()?.to_str()?;
+    let usable = !file_name.is_empty() && file_name != ".." && !Path::new(file_name).is_absolute();
+    usable.then_some(file_name)
+}
+
+fn attachment_name(attachment: &serde_json::Value) -> &str {
+    attachment["attributes"]["name"]
+        .as_str()
+        .or_else(|| attachment["name"].as_str())
+        .unwrap_or("")
+}
+
+/// Finds an attachment by ID or file name.
+fn find_attachment<'a>(
+    attachments: &'a [serde_json::Value],
+    value: &str,
+) -> Result<&'a serde_json::Value> {
+    if let Some(a) = attachments.iter().find(|a| a["id"].as_str() == Some(value)) {
+        return Ok(a);
+    }
+    let matches: Vec<&serde_json::Value> = attachments
+        .iter()
+        .filter(|a| attachment_name(a) == value)
+        .collect();
+    match matches.as_slice() {
+        [a] => Ok(a),
+        [] => bail!("unknown attachment '{value}'"),
+        _ => bail!("ambiguous attachment '{value}' — several files have that name, pass the ID"),
+    }
+}
+
+/// Guesses a MIME type from a file extension.
+fn content_type(path: &Path) -> &'static str {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "txt" | "log" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "html" | "htm" => "text/html",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "tar" => "application/x-tar",
+        _ => "application/octet-stream",
+    }
+}
+
+pub async fn attachments_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let attachments = fetch_attachments(client, workspace, project, issue).await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&attachments)?);
+        return Ok(());
+    }
+    if attachments.is_empty() {
+        println!("No attachments found.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    table.set_header(vec![
+        header("Name"),
+        header("Size"),
+        header("Uploaded"),
+        header("ID"),
+    ]);
+    for attachment in &attachments {
+        let size = attachment["attributes"]["size"]
+            .as_u64()
+            .map(|s| indicatif::HumanBytes(s).to_string())
+            .unwrap_or_default();
+        let when = attachment["created_at"]
+            .as_str()
+            .map(relative_time)
+            .unwrap_or_default();
+        table.add_row(vec![
+            Cell::new(attachment_name(attachment)).fg(Color::White),
+            Cell::new(size),
+            Cell::new(when),
+            Cell::new(attachment["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+        ]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+/// Uploads a file using Plane's presigned flow: request upload credentials,
+/// post the file to storage, then mark the attachment as uploaded.
+pub async fn attachments_upload(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    file: &Path,
+    json_mode: bool,
+) -> Result<()> {
+    let data = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+    let name = file
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .context("file path has no file name")?;
+    let mime = content_type(file);
+    let base =
+        format!("workspaces/{workspace}/projects/{project}/issues/{issue}/issue-attachments/");
+
+    let credentials = client
+        .post(
+            &base,
+            &serde_json::json!({ "name": name, "type": mime, "size": data.len() }),
+        )
+        .await?;
+    let url = credentials["upload_data"]["url"]
+        .as_str()
+        .context("unexpected response format: missing 'upload_data.url'")?;
+    let fields = credentials["upload_data"]["fields"]
+        .as_object()
+        .cloned()
+        .unwrap_or_default();
+    let asset_id = credentials["asset_id"]
+        .as_str()
+        .or_else(|| credentials["attachment"]["id"].as_str())
+        .context("unexpected response format: missing 'asset_id'")?;
+
+    let size = data.len() as u64;
+    client.upload(url, &fields, &name, mime, data).await?;
+
+    let confirmed = client
+        .patch(
+            &format!("{base}{asset_id}/"),
+            &serde_json::json!({ "is_uploaded": true }),
+        )
+        .await?;
+
+    if json_mode {
+        let out = if confirmed.is_null() {
+            credentials["attachment"].clone()
+        } else {
+            confirmed
+        };
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!(
+            "{} {name} ({})",
+            green.apply_to("Uploaded"),
+            indicatif::HumanBytes(size)
+        );
+        println!("  {}", dim.apply_to(asset_id));
+    }
+
+    Ok(())
+}
+
+/// Downloads an attachment (by ID or file name) to `output`, defaulting to
+/// its original file name in the current directory.
+pub async fn attachments_download(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    attachment: &str,
+    output: Option<&Path>,
+    json_mode: bool,
+) -> Result<()> {
+    let attachments = fetch_attachments(client, workspace, project, issue).await?;
+    let found = find_attachment(&attachments, attachment)?;
+    let id = found["id"].as_str().unwrap_or(attachment);
+    let name = attachment_name(found);
+
+    let file_name = || match safe_file_name(name) {
+        Some(file_name) => Ok(file_name),
+        None if name.is_empty() => bail!("attachment has no file name — pass --output"),
+        None => bail!("attachment name '{name}' is not a usable file name — pass --output"),
+    };
+    let dest = match output {
+        Some(path) if path.is_dir() => path.join(file_name()?),
+        Some(path) => path.to_path_buf(),
+        None => Path::new(file_name()?).to_path_buf(),
+    };
+
+    let size = client
+        .download(
+            &format!(
+                "workspaces/{workspace}/projects/{project}/issues/{issue}/issue-attachments/{id}/"
+            ),
+            &dest,
+        )
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "path": dest, "size": size });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        println!(
+            "{} {} ({})",
+            green.apply_to("Downloaded"),
+            dest.display(),
+            indicatif::HumanBytes(size)
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn attachments_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    attachment: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    let attachments = fetch_attachments(client, workspace, project, issue).await?;
+    let found = find_attachment(&attachments, attachment)?;
+    let id = found["id"].as_str().unwrap_or(attachment);
+
+    confirm(
+        &format!("Delete attachment {}?", attachment_name(found)),
+        yes,
+    )?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{issue}/issue-attachments/{id}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": id, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!(
+            "{} attachment {}",
+            red.apply_to("Deleted"),
+            attachment_name(found)
+        );
+    }
+
+    Ok(())
+}
+
+/// Parses a `--since` value: a date (`2025-01-31`) or a duration back from
+/// now (`30m`, `12h`, `7d`, `2w`).
+pub fn parse_since(value: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+        return midnight
+            .and_local_timezone(chrono::Local)
+            .earliest()
+            .map(|t| t.with_timezone(&chrono::Utc))
+            .with_context(|| format!("invalid date '{value}'"));
+    }
+
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let invalid =
+        || format!("invalid --since '{value}' — use a date like 2025-01-31 or a duration like 7d");
+    let amount: i64 = amount.parse().with_context(invalid)?;
+    let duration = match unit {
+        "m" => chrono::TimeDelta::try_minutes(amount),
+        "h" => chrono::TimeDelta::try_hours(amount),
+        "d" => chrono::TimeDelta::try_days(amount),
+        "w" => chrono::TimeDelta::try_weeks(amount),
+        _ => bail!(invalid()),
+    };
+    duration
+        .and_then(|duration| chrono::Utc::now().checked_sub_signed(duration))
+        .with_context(|| format!("invalid --since '{value}' — duration is too large"))
+}
+
+/// Maps the IDs of a project's states, labels and members to their names.
+async fn project_names(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+) -> Result<HashMap<String, String>> {
+    let mut names = state_names(client, workspace, project).await?;
+    let base = format!("workspaces/{workspace}/projects/{project}");
+    let mut add = |id: &serde_json::Value, name: &serde_json::Value| {
+        if let (Some(id), Some(name)) = (id.as_str(), name.as_str()) {
+            names.insert(id.to_string(), name.to_string());
+        }
+    };
+    for label in client.fetch_all(&format!("{base}/labels/")).await? {
+        add(&label["id"], &label["name"]);
+    }
+    for entry in client.fetch_all(&format!("{base}/members/")).await? {
+        let member = crate::resolve::project_member(&entry);
+        add(&member["id"], &member["display_name"]);
+    }
+    Ok(names)
+}
+
+/// Describes one activity entry, e.g. "Todo → In Progress".
+fn activity_change(activity: &serde_json::Value, names: &HashMap<String, String>) -> String {
+    let value = |value_key: &str, id_key: &str| -> String {
+        let raw = activity[value_key]
+            .as_str()
+            .filter(|v| !v.is_empty())
+            .or_else(|| activity[id_key].as_str())
+            .unwrap_or("");
+        names.get(raw).cloned().unwrap_or_else(|| raw.to_string())
+    };
+
+    if activity["field"].is_null() {
+        return activity["verb"].as_str().unwrap_or("updated").to_string();
+    }
+    match activity["field"].as_str().unwrap_or("") {
+        "description" => "edited the description".to_string(),
+        "comment" => "commented".to_string(),
+        _ => {
+            let old = value("old_value", "old_identifier");
+            let new = value("new_value", "new_identifier");
+            match (old.is_empty(), new.is_empty()) {
+                (true, false) => format!("+ {new}"),
+                (false, true) => format!("- {old}"),
+                _ => format!("{old} → {new}"),
+            }
+        }
+    }
+}
+
+pub async fn issues_activity(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut activities = Vec::new();
+    client
+        .paginate(
+            &format!("workspaces/{workspace}/projects/{project}/issues/{issue}/activities/"),
+            &[("per_page", "100")],
+            None,
+            None,
+            |items| {
+                activities.extend_from_slice(items);
+                Ok(())
+            },
+        )
+        .await?;
+
+    let created_at = |a: &serde_json::Value| {
+        a["created_at"]
+            .as_str()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.with_timezone(&chrono::Utc))
+    };
+    if let Some(since) = since {
+        activities.retain(|a| created_at(a).is_some_and(|t| t >= since));
+    }
+    activities.sort_by_key(created_at);
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&activities)?);
+        return Ok(());
+    }
+    if activities.is_empty() {
+        println!("No activity found.");
+        return Ok(());
+    }
+
+    let names = project_names(client, workspace, project).await?;
+    let mut table = Table::new();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    table.set_header(vec![
+        header("When"),
+        header("Who"),
+        header("Field"),
+        header("Change"),
+    ]);
+    for activity in &activities {
+        let when = created_at(activity)
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        let actor_id = activity["actor"].as_str().unwrap_or("");
+        let actor = activity["actor_detail"]["display_name"]
+            .as_str()
+            .or_else(|| names.get(actor_id).map(String::as_str))
+            .unwrap_or(actor_id);
+        table.add_row(vec![
+            Cell::new(when),
+            Cell::new(actor).fg(Color::White),
+            Cell::new(activity["field"].as_str().unwrap_or("")).fg(Color::Cyan),
+            Cell::new(activity_change(activity, &names)),
+        ]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+pub async fn worklogs_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    json_mode: bool,
+) -> Result<()> {
+    let worklogs = client
+        .fetch_all(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{issue}/worklogs/"
+        ))
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&worklogs)?);
+        return Ok(());
+    }
+    if worklogs.is_empty() {
+        println!("No worklogs found.");
+        return Ok(());
+    }
+
+    let names = project_names(client, workspace, project).await?;
+    let mut table = Table::new();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    table.set_header(vec![
+        header("When"),
+        header("Who"),
+        header("Duration"),
+        header("Description"),
+        header("ID"),
+    ]);
+    let mut total = 0;
+    for worklog in &worklogs {
+        let minutes = worklog["duration"].as_u64().unwrap_or(0);
+        total += minutes;
+        let who = worklog["logged_by"].as_str().unwrap_or("");
+        table.add_row(vec![
+            Cell::new(date_cell(&worklog["created_at"])),
+            Cell::new(names.get(who).map_or(who, String::as_str)).fg(Color::White),
+            Cell::new(duration::format_minutes(minutes)),
+            Cell::new(worklog["description"].as_str().unwrap_or("")),
+            Cell::new(worklog["id"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+        ]);
+    }
+    println!("{table}");
+    println!("Total: {}", duration::format_minutes(total));
+
+    Ok(())
+}
+
+pub async fn worklogs_add(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    minutes: u64,
+    description: Option<&str>,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .post(
+            &format!("workspaces/{workspace}/projects/{project}/issues/{issue}/worklogs/"),
+            &serde_json::json!({
+                "duration": minutes,
+                "description": description.unwrap_or(""),
+            }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!(
+            "{} {}",
+            green.apply_to("Logged"),
+            duration::format_minutes(minutes)
+        );
+        println!("  {}", dim.apply_to(data["id"].as_str().unwrap_or("")));
+    }
+
+    Ok(())
+}
+
+pub async fn worklogs_delete(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    worklog: &str,
+    yes: bool,
+    json_mode: bool,
+) -> Result<()> {
+    confirm(&format!("Delete worklog {worklog}?"), yes)?;
+
+    client
+        .delete(&format!(
+            "workspaces/{workspace}/projects/{project}/issues/{issue}/worklogs/{worklog}/"
+        ))
+        .await?;
+
+    if json_mode {
+        let out = serde_json::json!({ "id": worklog, "deleted": true });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let red = console::Style::new().red().bold();
+        println!("{} worklog {worklog}", red.apply_to("Deleted"));
+    }
+
+    Ok(())
+}
+
+/// What `worklog report` sums logged time by.
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ReportGroup {
+    Member,
+    Issue,
+    Project,
+}
+
+impl ReportGroup {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Member => "member",
+            Self::Issue => "issue",
+            Self::Project => "project",
+        }
+    }
+}
+
+pub struct WorklogReportParams<'a> {
+    /// Project IDs to include; all projects when empty.
+    pub projects: &'a [String],
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+    pub by: ReportGroup,
+    pub csv: bool,
+}
+
+/// Quotes a CSV field when it contains a separator, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Sums worklogs over a date range, grouped by member, issue or project.
+///
+/// Plane only lists worklogs per issue, so this walks every issue of each
+/// project; expect one request per issue.
+pub async fn worklog_report(
+    client: &Client,
+    workspace: &str,
+    params: &WorklogReportParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let projects: Vec<serde_json::Value> = client
+        .fetch_all(&format!("workspaces/{workspace}/projects/"))
+        .await?
+        .into_iter()
+        .filter(|p| {
+            params.projects.is_empty()
+                || p["id"]
+                    .as_str()
+                    .is_some_and(|id| params.projects.iter().any(|w| w == id))
+        })
+        .collect();
+
+    // group key -> (minutes, entries)
+    let mut totals: std::collections::BTreeMap<String, (u64, usize)> = Default::default();
+    for project in &projects {
+        let project_id = project["id"].as_str().unwrap_or_default();
+        let identifier = project["identifier"].as_str().unwrap_or_default();
+        let project_label = project["name"].as_str().unwrap_or(identifier).to_string();
+        let base = format!("workspaces/{workspace}/projects/{project_id}");
+
+        let issues = client.fetch_all(&format!("{base}/issues/")).await?;
+        let members: HashMap<String, String> = if params.by == ReportGroup::Member {
+            project_names(client, workspace, project_id).await?
+        } else {
+            HashMap::new()
+        };
+
+        for issue in &issues {
+            let issue_id = issue["id"].as_str().unwrap_or_default();
+            let worklogs = client
+                .fetch_all(&format!("{base}/issues/{issue_id}/worklogs/"))
+                .await?;
+            for worklog in worklogs {
+                let date = worklog["created_at"]
+                    .as_str()
+                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                    .map(|t| t.with_timezone(&chrono::Local).date_naive());
+                let in_range = date.is_some_and(|d| {
+                    params.from.is_none_or(|from| d >= from) && params.to.is_none_or(|to| d <= to)
+                });
+                if !in_range {
+                    continue;
+                }
+
+                let key = match params.by {
+                    ReportGroup::Member => {
+                        let who = worklog["logged_by"].as_str().unwrap_or_default();
+                        members.get(who).cloned().unwrap_or_else(|| who.to_string())
+                    }
+                    ReportGroup::Issue => format!(
+                        "{identifier}-{} {}",
+                        issue["sequence_id"],
+                        issue["name"].as_str().unwrap_or_default()
+                    ),
+                    ReportGroup::Project => project_label.clone(),
+                };
+                let entry = totals.entry(key).or_default();
+                entry.0 += worklog["duration"].as_u64().unwrap_or(0);
+                entry.1 += 1;
+            }
+        }
+    }
+
+    let total_minutes: u64 = totals.values().map(|(m, _)| m).sum();
+    let group = params.by.as_str();
+
+    if params.csv {
+        println!("{group},minutes,hours,entries");
+        for (key, (minutes, entries)) in &totals {
+            println!(
+                "{},{minutes},{:.2},{entries}",
+                csv_field(key),
+                *minutes as f64 / 60.0
+            );
+        }
+        return Ok(());
+    }
+
+    if json_mode {
+        let rows: Vec<serde_json::Value> = totals
+            .iter()
+            .map(|(key, (minutes, entries))| {
+                serde_json::json!({ group: key, "minutes": minutes, "entries": entries })
+            })
+            .collect();
+        let out = serde_json::json!({
+            "group_by": group,
+            "from": params.from.map(|d| d.to_string()),
+            "to": params.to.map(|d| d.to_string()),
+            "total_minutes": total_minutes,
+            "rows": rows,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    if totals.is_empty() {
+        println!("No worklogs found.");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_BORDERS_ONLY);
+    let mut title = group.to_string();
+    title[..1].make_ascii_uppercase();
+    table.set_header(vec![header(&title), header("Time"), header("Entries")]);
+    for (key, (minutes, entries)) in &totals {
+        table.add_row(vec![
+            Cell::new(key).fg(Color::White),
+            Cell::new(duration::format_minutes(*minutes)),
+            Cell::new(entries),
+        ]);
+    }
+    println!("{table}");
+    println!("Total: {}", duration::format_minutes(total_minutes));
+
+    Ok(())
+}
+
+/// Intake status filter for `intake list`.
+#[derive(Clone, Copy, clap::ValueEnum)]
+pub enum IntakeStatus {
+    Pending,
+    Accepted,
+    Declined,
+    Snoozed,
+    Duplicate,
+    All,
+}
+
+impl IntakeStatus {
+    /// Plane's numeric status code, or `None` for all items.
+    fn code(self) -> Option<i64> {
+        match self {
+            Self::Pending => Some(-2),
+            Self::Declined => Some(-1),
+            Self::Snoozed => Some(0),
+            Self::Accepted => Some(1),
+            Self::Duplicate => Some(2),
+            Self::All => None,
+        }
+    }
+}
+
+fn intake_status_cell(code: Option<i64>) -> Cell {
+    let (label, color) = match code {
+        Some(-2) => ("pending", Color::Yellow),
+        Some(-1) => ("declined", Color::Red),
+        Some(0) => ("snoozed", Color::Blue),
+        Some(1) => ("accepted", Color::Green),
+        Some(2) => ("duplicate", Color::DarkGrey),
+        _ => ("unknown", Color::Reset),
+    };
+    Cell::new(label).fg(color)
+}
+
+pub async fn intake_list(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    status: IntakeStatus,
+    paging: &Paging<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let code = status.code().map(|c| c.to_string());
+    let mut query = Vec::new();
+    if let Some(c) = &code {
+        query.push(("status", c.as_str()));
+    }
+
+    list_paged(
+        client,
+        &format!("workspaces/{workspace}/projects/{project}/intake-issues/"),
+        query,
+        paging,
+        ListView {
+            noun: "intake issues",
+            headers: vec![
+                header("Title"),
+                header("Status"),
+                header("Priority"),
+                header("Received"),
+                header("Issue ID"),
+            ],
+            row: |item| {
+                let issue = &item["issue_detail"];
+                vec![
+                    Cell::new(issue["name"].as_str().unwrap_or("(untitled)")).fg(Color::White),
+                    intake_status_cell(item["status"].as_i64()),
+                    priority_cell(issue["priority"].as_str().unwrap_or("none")),
+                    Cell::new(
+                        item["created_at"]
+                            .as_str()
+                            .map(relative_time)
+                            .unwrap_or_default(),
+                    ),
+                    Cell::new(item["issue"].as_str().unwrap_or("")).fg(Color::DarkGrey),
+                ]
+            },
+        },
+        json_mode,
+    )
+    .await
+}
+
+pub struct IntakeCreateParams<'a> {
+    pub project: &'a str,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub priority: Option<&'a str>,
+}
+
+pub async fn intake_create(
+    client: &Client,
+    workspace: &str,
+    params: &IntakeCreateParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let mut issue = serde_json::json!({ "name": params.title });
+    let obj = issue.as_object_mut().unwrap();
+
+    if let Some(d) = params.description {
+        obj.insert("description_html".to_string(), serde_json::json!(d));
+    }
+    if let Some(p) = params.priority {
+        obj.insert("priority".to_string(), serde_json::json!(p));
+    }
+
+    let data = client
+        .post(
+            &format!(
+                "workspaces/{workspace}/projects/{}/intake-issues/",
+                params.project
+            ),
+            &serde_json::json!({ "issue": issue }),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        println!(
+            "{} intake issue {}",
+            green.apply_to("Created"),
+            params.title
+        );
+        println!(
+            "  {}",
+            dim.apply_to(
+                data["issue"]
+                    .as_str()
+                    .or_else(|| data["id"].as_str())
+                    .unwrap_or("")
+            )
+        );
+    }
+
+    Ok(())
+}
+
+/// A triage decision on an intake issue.
+pub enum IntakeDecision<'a> {
+    Accept,
+    Decline,
+    /// Snooze until the given RFC 3339 timestamp.
+    Snooze(&'a str),
+    /// Mark as a duplicate of the given issue ID.
+    Duplicate(&'a str),
+}
+
+impl IntakeDecision<'_> {
+    fn body(&self) -> serde_json::Value {
+        match self {
+            Self::Accept => serde_json::json!({ "status": 1 }),
+            Self::Decline => serde_json::json!({ "status": -1 }),
+            Self::Snooze(until) => serde_json::json!({ "status": 0, "snoozed_till": until }),
+            Self::Duplicate(of) => serde_json::json!({ "status": 2, "duplicate_to": of }),
+        }
+    }
+
+    fn verb(&self) -> &'static str {
+        match self {
+            Self::Accept => "Accepted",
+            Self::Decline => "Declined",
+            Self::Snooze(_) => "Snoozed",
+            Self::Duplicate(_) => "Marked as duplicate",
+        }
+    }
+}
+
+pub async fn intake_decide(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    issue: &str,
+    decision: &IntakeDecision<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let data = client
+        .patch(
+            &format!("workspaces/{workspace}/projects/{project}/intake-issues/{issue}/"),
+            &decision.body(),
+        )
+        .await?;
+
+    if json_mode {
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let name = data["issue_detail"]["name"].as_str().unwrap_or(issue);
+        print!("{} {name}", green.apply_to(decision.verb()));
+        match decision {
+            IntakeDecision::Snooze(until) => println!(" until {}", &until[..10.min(until.len())]),
+            _ => println!(),
+        }
+    }
+
+    Ok(())
+}
+
+pub struct ApplyParams<'a> {
+    pub project: &'a str,
+    pub manifest: &'a Manifest,
+    /// Print the plan without changing anything.
+    pub dry_run: bool,
+    /// Delete objects that aren't in the manifest.
+    pub prune: bool,
+    pub yes: bool,
+}
+
+fn print_plan(changes: &[manifest::Change]) {
+    let dim = console::Style::new().dim();
+    for change in changes {
+        let (sign, style) = match change.action {
+            Action::Create => ("+", console::Style::new().green()),
+            Action::Update => ("~", console::Style::new().yellow()),
+            Action::Delete => ("-", console::Style::new().red()),
+        };
+        println!(
+            "{} {} {}",
+            style.apply_to(sign),
+            change.kind.as_str(),
+            console::Style::new().bold().apply_to(&change.name)
+        );
+        for (field, old, new) in &change.diffs {
+            let line = match change.action {
+                Action::Create => format!("{field}: {new}"),
+                _ => format!("{field}: {old} → {new}"),
+            };
+            println!("    {}", dim.apply_to(line));
+        }
+    }
+
+    let count = |action| changes.iter().filter(|c| c.action == action).count();
+    println!(
+        "Plan: {} to create, {} to update, {} to delete.",
+        count(Action::Create),
+        count(Action::Update),
+        count(Action::Delete)
+    );
+}
+
+/// Diffs a manifest against the live project and applies the difference.
+pub async fn apply(
+    client: &Client,
+    workspace: &str,
+    params: &ApplyParams<'_>,
+    json_mode: bool,
+) -> Result<()> {
+    let manifest = params.manifest;
+    let base = format!("workspaces/{workspace}/projects/{}", params.project);
+    let mut live = manifest::Live::default();
+    if manifest.manages_states() {
+        live.states = client.fetch_all(&format!("{base}/states/")).await?;
+    }
+    if manifest.manages_labels() {
+        live.labels = client.fetch_all(&format!("{base}/labels/")).await?;
+    }
+    if manifest.manages_modules() {
+        live.modules = client.fetch_all(&format!("{base}/modules/")).await?;
+    }
+    if manifest.manages_members() {
+        live.members = client
+            .fetch_all(&format!("{base}/members/"))
+            .await?
+            .iter()
+            .map(crate::resolve::project_member)
+            .collect();
+    }
+    if manifest.needs_workspace_members() {
+        live.workspace_members = client
+            .fetch_all(&format!("workspaces/{workspace}/members/"))
+            .await?;
+    }
+
+    let changes = manifest::plan(manifest, &live, params.prune)?;
+
+    // The server refuses to delete a state that still has issues. A dry run
+    // reports those states; a real run stops before anything is changed.
+    let mut blocked = Vec::new();
+    for change in &changes {
+        if change.kind == Kind::State && change.action == Action::Delete {
+            let id = change.id.as_deref().unwrap_or_default();
+            let count = state_issues(client, &base, id).await?.len();
+            if count > 0 {
+                blocked.push(format!(
+                    "state '{}' still has {count} issue(s) — move them with `states delete --move-to` or keep the state in the manifest",
+                    change.name
+                ));
+            }
+        }
+    }
+    let report = |applied: usize| -> Result<()> {
+        let out = serde_json::json!({
+            "project": params.project,
+            "dry_run": params.dry_run,
+            "changes": changes.iter().map(manifest::Change::to_json).collect::<Vec<_>>(),
+            "warnings": blocked,
+            "applied": applied,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        Ok(())
+    };
+
+    if changes.is_empty() {
+        if json_mode {
+            return report(0);
+        }
+        println!("No changes — the project matches the manifest.");
+        return Ok(());
+    }
+    if !json_mode {
+        print_plan(&changes);
+    }
+    if params.dry_run {
+        if json_mode {
+            return report(0);
+        }
+        let yellow = console::Style::new().yellow().bold();
+        for warning in &blocked {
+            println!("{} {warning}", yellow.apply_to("warning:"));
+        }
+        return Ok(());
+    }
+    if !blocked.is_empty() {
+        bail!("{}", blocked.join("\n"));
+    }
+
+    let deletes = changes
+        .iter()
+        .filter(|c| c.action == Action::Delete)
+        .count();
+    if deletes > 0 {
+        confirm(
+            &format!(
+                "Apply {} change(s), including {deletes} deletion(s)?",
+                changes.len()
+            ),
+            params.yes,
+        )?;
+    }
+
+    // Label parents are referenced by name and may be created along the way.
+    let mut label_ids: HashMap<String, String> = live
+        .labels
+        .iter()
+        .filter_map(|l| {
+            Some((
+                l["name"].as_str()?.to_lowercase(),
+                l["id"].as_str()?.to_string(),
+            ))
+        })
+        .collect();
+
+    for change in &changes {
+        let path = format!("{base}/{}/", change.kind.path());
+        let mut body = change.body.clone();
+        if let Some(parent) = &change.parent {
+            let id = label_ids
+                .get(&parent.to_lowercase())
+                .with_context(|| format!("parent label '{parent}' not found"))?;
+            body.insert("parent".to_string(), serde_json::json!(id));
+        }
+        let body = serde_json::Value::Object(body);
+        let id = change.id.as_deref().unwrap_or_default();
+
+        let result = match change.action {
+            Action::Create => client.post(&path, &body).await,
+            Action::Update => client.patch(&format!("{path}{id}/"), &body).await,
+            Action::Delete => client.delete(&format!("{path}{id}/")).await,
+        };
+        let data = result.with_context(|| {
+            format!(
+                "failed to {} {} '{}'",
+                change.action.as_str(),
+                change.kind.as_str(),
+                change.name
+            )
+        })?;
+
+        if change.kind == Kind::Label
+            && change.action == Action::Create
+            && let Some(id) = data["id"].as_str()
+        {
+            label_ids.insert(change.name.to_lowercase(), id.to_string());
+        }
+    }
+
+    if json_mode {
+        report(changes.len())
+    } else {
+        let green = console::Style::new().green().bold();
+        println!("{} {} change(s)", green.apply_to("Applied"), changes.len());
+        Ok(())
+    }
+}
+
+/// Layout version of `plane export` archives, bumped on incompatible changes.
+const EXPORT_VERSION: u32 = 1;
+
+/// Archive format for `plane export`.
+#[derive(Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// One JSON document holding the manifest and every collection
+    Json,
+    /// A tar of `manifest.json` plus one JSON file per collection
+    Tar,
+}
+
+/// Packs the manifest and collections into a tar, all under `dir/`.
+fn export_tar(dir: &str, sections: &[(&str, serde_json::Value)]) -> Result<Vec<u8>> {
+    let mtime = chrono::Utc::now().timestamp().max(0) as u64;
+    let mut builder = tar::Builder::new(Vec::new());
+    for (name, value) in sections {
+        let data = serde_json::to_vec_pretty(value)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(mtime);
+        header.set_cksum();
+        builder.append_data(&mut header, format!("{dir}/{name}.json"), data.as_slice())?;
+    }
+    Ok(builder.into_inner()?)
+}
+
+/// Dumps a project and everything in it into a versioned archive with a
+/// manifest, following pagination for every list.
+///
+/// Per-issue data (comments, links, relations) and cycle/module membership
+/// are keyed by the owning issue, cycle or module ID.
+pub async fn export(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    format: ExportFormat,
+    output: Option<&Path>,
+    json_mode: bool,
+) -> Result<()> {
+    let base = format!("workspaces/{workspace}/projects/{project}");
+    let details = client.get(&format!("{base}/")).await?;
+    let states = client.fetch_all(&format!("{base}/states/")).await?;
+    let labels = client.fetch_all(&format!("{base}/labels/")).await?;
+    let members = client.fetch_all(&format!("{base}/members/")).await?;
+    let cycles = client.fetch_all(&format!("{base}/cycles/")).await?;
+    let modules = client.fetch_all(&format!("{base}/modules/")).await?;
+    let issues = client.fetch_all(&format!("{base}/issues/")).await?;
+
+    let mut comments = serde_json::Map::new();
+    let mut links = serde_json::Map::new();
+    let mut relations = serde_json::Map::new();
+    for issue in &issues {
+        let Some(id) = issue["id"].as_str() else {
+            continue;
+        };
+        let context = || format!("failed to export issue {id}");
+        let issue_comments = client
+            .fetch_all(&format!("{base}/issues/{id}/comments/"))
+            .await
+            .with_context(context)?;
+        let issue_links = fetch_links(client, workspace, project, id)
+            .await
+            .with_context(context)?;
+        let issue_relations = client
+            .get(&format!("{base}/issues/{id}/issue-relation/"))
+            .await
+            .with_context(context)?;
+        comments.insert(id.to_string(), serde_json::json!(issue_comments));
+        links.insert(id.to_string(), serde_json::json!(issue_links));
+        relations.insert(id.to_string(), issue_relations);
+    }
+
+    // Membership as issue IDs; the list endpoints return either issues or
+    // join rows with an `issue` field.
+    let membership = |items: Vec<serde_json::Value>| -> serde_json::Value {
+        items
+            .iter()
+            .filter_map(|i| i["issue"].as_str().or_else(|| i["id"].as_str()))
+            .collect::<Vec<_>>()
+            .into()
+    };
+    let mut cycle_issues = serde_json::Map::new();
+    for cycle in cycles.iter().filter_map(|c| c["id"].as_str()) {
+        let items = client
+            .fetch_all(&format!("{base}/cycles/{cycle}/cycle-issues/"))
+            .await?;
+        cycle_issues.insert(cycle.to_string(), membership(items));
+    }
+    let mut module_issues = serde_json::Map::new();
+    for module in modules.iter().filter_map(|m| m["id"].as_str()) {
+        let items = client
+            .fetch_all(&format!("{base}/modules/{module}/module-issues/"))
+            .await?;
+        module_issues.insert(module.to_string(), membership(items));
+    }
+
+    let count_nested = |map: &serde_json::Map<String, serde_json::Value>| -> usize {
+        map.values().map(|v| v.as_array().map_or(0, Vec::len)).sum()
+    };
+    let identifier = details["identifier"]
+        .as_str()
+        .unwrap_or(project)
+        .to_string();
+    let manifest = serde_json::json!({
+        "format": "plane-cli-export",
+        "version": EXPORT_VERSION,
+        "generator": concat!("plane-cli ", env!("CARGO_PKG_VERSION")),
+        "exported_at": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        "workspace": workspace,
+        "project": {
+            "id": details["id"],
+            "identifier": identifier,
+            "name": details["name"],
+        },
+        "counts": {
+            "states": states.len(),
+            "labels": labels.len(),
+            "members": members.len(),
+            "cycles": cycles.len(),
+            "modules": modules.len(),
+            "issues": issues.len(),
+            "comments": count_nested(&comments),
+            "links": count_nested(&links),
+        },
+    });
+
+    let sections = [
+        ("manifest", manifest.clone()),
+        ("project", details),
+        ("states", states.into()),
+        ("labels", labels.into()),
+        ("members", members.into()),
+        ("cycles", cycles.into()),
+        ("modules", modules.into()),
+        ("issues", issues.into()),
+        ("comments", comments.into()),
+        ("links", links.into()),
+        ("relations", relations.into()),
+        ("cycle_issues", cycle_issues.into()),
+        ("module_issues", module_issues.into()),
+    ];
+
+    // The identifier comes from the server, so keep it from steering the
+    // output path or the archive's entry names.
+    let stem = format!("{identifier}-{}", chrono::Local::now().format("%Y-%m-%d"));
+    let stem = safe_file_name(&stem).with_context(|| {
+        format!("project identifier '{identifier}' is not a usable file name — pass --output")
+    })?;
+    let (bytes, extension) = match format {
+        ExportFormat::Json => {
+            let document: serde_json::Map<String, serde_json::Value> = sections
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect();
+            (serde_json::to_vec_pretty(&document)?, "json")
+        }
+        ExportFormat::Tar => (export_tar(stem, &sections)?, "tar"),
+    };
+
+    let default_path;
+    let path = match output {
+        Some(path) => path,
+        None => {
+            default_path = std::path::PathBuf::from(format!("{stem}.{extension}"));
+            &default_path
+        }
+    };
+    if path == Path::new("-") {
+        std::io::stdout()
+            .write_all(&bytes)
+            .context("failed to write to stdout")?;
+        return Ok(());
+    }
+    // Write next to the target and rename, so a failed export never leaves a
+    // truncated archive under the final name.
+    let name = path
+        .file_name()
+        .map_or_else(String::new, |n| n.to_string_lossy().into_owned());
+    let part = path.with_file_name(format!(".{name}.part"));
+    if let Err(err) = std::fs::write(&part, &bytes).and_then(|()| std::fs::rename(&part, path)) {
+        let _ = std::fs::remove_file(&part);
+        return Err(err).with_context(|| format!("failed to write {}", path.display()));
+    }
+
+    if json_mode {
+        let mut out = manifest;
+        out["path"] = serde_json::json!(path.display().to_string());
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        let green = console::Style::new().green().bold();
+        let dim = console::Style::new().dim();
+        let counts = &manifest["counts"];
+        println!(
+            "{} {identifier} to {}",
+            green.apply_to("Exported"),
+            path.display()
+        );
+        println!(
+            "  {}",
+            dim.apply_to(format!(
+                "{} issues, {} comments, {} links, {} cycles, {} modules, {} states, {} labels, {} members",
+                counts["issues"],
+                counts["comments"],
+                counts["links"],
+                counts["cycles"],
+                counts["modules"],
+                counts["states"],
+                counts["labels"],
+                counts["members"],
+            ))
+        );
     }
 
     Ok(())
@@ -0,0 +1,86 @@
+//! Human-readable durations for worklogs, stored by Plane in whole minutes.
+
+use anyhow::{Result, bail};
+
+/// Parses a duration like `1h30m`, `2h`, `45m`, `1.5h` or `1d` (8 hours) into
+/// minutes. A bare number is taken as minutes.
+pub fn parse_minutes(input: &str) -> Result<u64> {
+    let input = input.trim().to_lowercase().replace(' ', "");
+    if input.is_empty() {
+        bail!("duration is empty");
+    }
+    let minutes = match input.parse::<u64>() {
+        Ok(minutes) => minutes,
+        Err(_) => parse_units(&input)?,
+    };
+    if minutes == 0 {
+        bail!("duration must be at least one minute");
+    }
+    Ok(minutes)
+}
+
+fn parse_units(input: &str) -> Result<u64> {
+    let mut total = 0.0;
+    let mut number = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+        let Ok(value) = number.parse::<f64>() else {
+            bail!("invalid duration '{input}' — use e.g. 1h30m, 45m or 1.5h");
+        };
+        total += value
+            * match c {
+                'd' => 8.0 * 60.0,
+                'h' => 60.0,
+                'm' => 1.0,
+                _ => bail!("invalid duration '{input}' — use e.g. 1h30m, 45m or 1.5h"),
+            };
+        number.clear();
+    }
+    if !number.is_empty() {
+        bail!("invalid duration '{input}' — missing unit after {number}");
+    }
+
+    Ok(total.round() as u64)
+}
+
+/// Formats minutes as `1h 30m`, `2h` or `45m`.
+pub fn format_minutes(minutes: u64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h {m}m"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_minutes() {
+        assert_eq!(parse_minutes("1h30m").unwrap(), 90);
+        assert_eq!(parse_minutes("2h").unwrap(), 120);
+        assert_eq!(parse_minutes("45m").unwrap(), 45);
+        assert_eq!(parse_minutes("1.5h").unwrap(), 90);
+        assert_eq!(parse_minutes("1d").unwrap(), 480);
+        assert_eq!(parse_minutes("1H 15M").unwrap(), 75);
+        assert_eq!(parse_minutes("20").unwrap(), 20);
+    }
+
+    #[test]
+    fn test_parse_minutes_rejects_invalid() {
+        for input in ["", "abc", "1x", "1h30", "h", "0m", "0"] {
+            assert!(parse_minutes(input).is_err(), "accepted {input:?}");
+        }
+    }
+
+    #[test]
+    fn test_format_minutes() {
+        assert_eq!(format_minutes(45), "45m");
+        assert_eq!(format_minutes(120), "2h");
+        assert_eq!(format_minutes(90), "1h 30m");
+    }
+}
@@ -0,0 +1,970 @@
+//! Minimal HTML handling for the rich-text fields Plane uses
+//! (`description_html`, `comment_html`).
+
+/// A piece of tokenized HTML.
+#[derive(Debug, PartialEq)]
+pub enum Token {
+    Text(String),
+    Open {
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
+    Close(String),
+}
+
+/// Splits HTML into text and tag tokens. Tag names are lowercased, entities in
+/// text and attribute values are decoded, and comments are dropped.
+/// Self-closing tags (`<br/>`) produce an `Open` token only.
+pub fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        if lt > 0 {
+            tokens.push(Token::Text(decode_entities(&rest[..lt])));
+        }
+        rest = &rest[lt..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(gt) = rest.find('>') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        let tag = rest[1..gt].trim();
+        rest = &rest[gt + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+        } else {
+            let tag = tag.trim_end_matches('/').trim_end();
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open {
+                name: name.to_lowercase(),
+                attrs: parse_attrs(attrs),
+            });
+        }
+    }
+
+    tokens
+}
+
+fn parse_attrs(mut input: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    loop {
+        input = input.trim_start();
+        if input.is_empty() {
+            break;
+        }
+        let name_end = input
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(input.len());
+        let name = input[..name_end].to_lowercase();
+        input = input[name_end..].trim_start();
+
+        let value = if let Some(after_eq) = input.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            match after_eq.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let body = &after_eq[1..];
+                    let end = body.find(q).unwrap_or(body.len());
+                    input = body.get(end + 1..).unwrap_or("");
+                    &body[..end]
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    input = &after_eq[end..];
+                    &after_eq[..end]
+                }
+            }
+        } else {
+            ""
+        };
+        attrs.push((name, decode_entities(value)));
+    }
+    attrs
+}
+
+/// Decodes the named entities Plane's editor emits plus numeric references.
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" | "#39" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_block(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "div"
+            | "ul"
+            | "ol"
+            | "li"
+            | "pre"
+            | "blockquote"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "table"
+            | "tr"
+    )
+}
+
+/// Flattens HTML into plain text: block elements become line breaks, list
+/// items get a `- ` bullet, everything else is reduced to its text.
+pub fn to_plain_text(html: &str) -> String {
+    let mut out = String::new();
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => out.push_str(&text),
+            Token::Open { name, .. } if name == "br" => out.push('\n'),
+            Token::Open { name, .. } if name == "li" => {
+                ensure_newline(&mut out);
+                out.push_str("- ");
+            }
+            Token::Open { name, .. } | Token::Close(name) if is_block(&name) => {
+                ensure_newline(&mut out);
+            }
+            _ => {}
+        }
+    }
+
+    let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    let mut text = String::new();
+    for line in lines.iter().skip_while(|l| l.is_empty()) {
+        if line.is_empty() && text.ends_with("\n\n") {
+            continue;
+        }
+        text.push_str(line);
+        text.push('\n');
+    }
+    text.trim_end().to_string()
+}
+
+fn ensure_newline(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+/// A list being rendered: bullets, numbers, or checkboxes per item.
+struct List {
+    ordered: bool,
+    next: u64,
+}
+
+impl List {
+    fn marker(&mut self) -> String {
+        if self.ordered {
+            self.next += 1;
+            format!("{}.", self.next - 1)
+        } else {
+            "-".to_string()
+        }
+    }
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attrs
+        .iter()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.as_str())
+}
+
+fn list_for(name: &str, attrs: &[(String, String)]) -> List {
+    List {
+        ordered: name == "ol",
+        next: attr(attrs, "start")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(1),
+    }
+}
+
+/// Returns the checkbox marker for a task item, if `li` is one.
+fn task_marker(attrs: &[(String, String)]) -> Option<&'static str> {
+    (attr(attrs, "data-type") == Some("taskItem")).then(|| {
+        if attr(attrs, "data-checked") == Some("true") {
+            "[x]"
+        } else {
+            "[ ]"
+        }
+    })
+}
+
+/// The text shown for a mention: Plane's `<mention-component>` carries only
+/// attributes, older editors used `<span data-type="mention" data-label=..>`.
+fn mention_label(name: &str, attrs: &[(String, String)]) -> Option<String> {
+    if name != "mention-component" {
+        return None;
+    }
+    let label = attr(attrs, "label")
+        .or_else(|| attr(attrs, "entity_name").filter(|n| !n.ends_with("_mention")))
+        .or_else(|| attr(attrs, "entity_identifier"))
+        .unwrap_or("");
+    Some(format!("@{label}"))
+}
+
+fn collapse_whitespace(text: &str, out: &mut String) {
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !out.is_empty() && !out.ends_with(' ') && !out.ends_with('\n') {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+// ── Terminal rendering ──
+
+/// A word made of differently styled pieces, or a forced line break.
+enum Word {
+    Text(Vec<(String, console::Style)>),
+    Break,
+}
+
+/// Renders HTML as styled terminal text wrapped at `width` columns.
+struct TerminalRenderer {
+    width: usize,
+    lines: Vec<String>,
+    fragments: Vec<(String, console::Style)>,
+    bold: u32,
+    italic: u32,
+    code: u32,
+    strike: u32,
+    heading: Option<u8>,
+    links: Vec<(String, usize)>,
+    lists: Vec<List>,
+    marker: Option<String>,
+    quote: usize,
+    pre: Option<String>,
+    first_cell: bool,
+}
+
+impl TerminalRenderer {
+    fn new(width: usize) -> Self {
+        Self {
+            width,
+            lines: Vec::new(),
+            fragments: Vec::new(),
+            bold: 0,
+            italic: 0,
+            code: 0,
+            strike: 0,
+            heading: None,
+            links: Vec::new(),
+            lists: Vec::new(),
+            marker: None,
+            quote: 0,
+            pre: None,
+            first_cell: true,
+        }
+    }
+
+    fn style(&self) -> console::Style {
+        let mut style = console::Style::new();
+        if self.bold > 0 || self.heading.is_some() {
+            style = style.bold();
+        }
+        if self.heading == Some(1) || !self.links.is_empty() {
+            style = style.underlined();
+        }
+        if self.italic > 0 {
+            style = style.italic();
+        }
+        if self.strike > 0 {
+            style = style.strikethrough();
+        }
+        if self.code > 0 {
+            style = style.yellow();
+        } else if !self.links.is_empty() {
+            style = style.blue();
+        }
+        style
+    }
+
+    fn push(&mut self, text: &str, style: console::Style) {
+        self.fragments.push((text.to_string(), style));
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some(pre) = &mut self.pre {
+            pre.push_str(text);
+            return;
+        }
+        let mut collapsed = String::new();
+        let prev_space = self
+            .fragments
+            .last()
+            .is_none_or(|(t, _)| t.ends_with(' ') || t.ends_with('\n'));
+        for c in text.chars() {
+            if c.is_whitespace() {
+                let at_start = collapsed.is_empty() && prev_space;
+                if !at_start && !collapsed.ends_with(' ') {
+                    collapsed.push(' ');
+                }
+            } else {
+                collapsed.push(c);
+            }
+        }
+        if !collapsed.is_empty() {
+            let style = self.style();
+            self.push(&collapsed, style);
+        }
+    }
+
+    fn prefix(&self) -> String {
+        let quote = "│ ".repeat(self.quote);
+        let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+        format!("{quote}{indent}")
+    }
+
+    fn blank(&mut self) {
+        if self.lines.last().is_some_and(|l| !l.trim().is_empty()) {
+            let quote = "│ ".repeat(self.quote);
+            self.lines.push(quote.trim_end().to_string());
+        }
+    }
+
+    fn words(&mut self) -> Vec<Word> {
+        let mut words = Vec::new();
+        let mut current: Vec<(String, console::Style)> = Vec::new();
+        for (text, style) in self.fragments.drain(..) {
+            for c in text.chars() {
+                if c == '\n' || c == ' ' {
+                    if !current.is_empty() {
+                        words.push(Word::Text(std::mem::take(&mut current)));
+                    }
+                    if c == '\n' {
+                        words.push(Word::Break);
+                    }
+                    continue;
+                }
+                match current.last_mut() {
+                    Some((piece, s)) if *s == style => piece.push(c),
+                    _ => current.push((c.to_string(), style.clone())),
+                }
+            }
+        }
+        if !current.is_empty() {
+            words.push(Word::Text(current));
+        }
+        words
+    }
+
+    /// Wraps the pending inline fragments into lines.
+    fn flush(&mut self) {
+        let words = self.words();
+        let marker = if words.is_empty() {
+            None
+        } else {
+            self.marker.take()
+        };
+        if words.is_empty() {
+            return;
+        }
+
+        let base = self.prefix();
+        let (first, rest) = match &marker {
+            Some(m) => (
+                format!("{base}{m} "),
+                format!("{base}{}", " ".repeat(m.len() + 1)),
+            ),
+            None if !self.lists.is_empty() => (format!("{base}  "), format!("{base}  ")),
+            None => (base.clone(), base),
+        };
+        let available = self
+            .width
+            .saturating_sub(console::measure_text_width(&first))
+            .max(20);
+
+        let mut line = first;
+        let mut line_width = 0;
+        for word in words {
+            let pieces = match word {
+                Word::Break => {
+                    self.lines.push(std::mem::replace(&mut line, rest.clone()));
+                    line_width = 0;
+                    continue;
+                }
+                Word::Text(pieces) => pieces,
+            };
+            let width: usize = pieces
+                .iter()
+                .map(|(t, _)| console::measure_text_width(t))
+                .sum();
+            if line_width > 0 && line_width + 1 + width > available {
+                self.lines.push(std::mem::replace(&mut line, rest.clone()));
+                line_width = 0;
+            }
+            if line_width > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+            for (text, style) in pieces {
+                line.push_str(&style.apply_to(text).to_string());
+            }
+            line_width += width;
+        }
+        self.lines.push(line);
+    }
+
+    fn open(&mut self, name: &str, attrs: &[(String, String)]) {
+        if self.pre.is_some() {
+            return;
+        }
+        if let Some(label) = mention_label(name, attrs) {
+            self.push(&label, console::Style::new().cyan());
+            return;
+        }
+        match name {
+            "p" | "div" => self.flush(),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.blank();
+                self.heading = name[1..].parse().ok();
+            }
+            "ul" | "ol" => {
+                self.flush();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+                self.lists.push(list_for(name, attrs));
+            }
+            "li" => {
+                self.flush();
+                self.marker = Some(match task_marker(attrs) {
+                    Some(m) => m.to_string(),
+                    None => self.lists.last_mut().map_or("-".to_string(), List::marker),
+                });
+            }
+            "input" if attr(attrs, "type") == Some("checkbox") => {
+                let checked = attrs.iter().any(|(k, _)| k == "checked");
+                self.marker = Some(if checked { "[x]" } else { "[ ]" }.to_string());
+            }
+            "blockquote" => {
+                self.flush();
+                self.blank();
+                self.quote += 1;
+            }
+            "pre" => {
+                self.flush();
+                self.blank();
+                self.pre = Some(String::new());
+            }
+            "br" => self.push("\n", console::Style::new()),
+            "hr" => {
+                self.flush();
+                self.blank();
+                let rule = "─".repeat(self.width.min(40));
+                self.lines
+                    .push(console::Style::new().dim().apply_to(rule).to_string());
+                self.blank();
+            }
+            "strong" | "b" | "th" => self.bold += 1,
+            "em" | "i" => self.italic += 1,
+            "code" => self.code += 1,
+            "s" | "del" | "strike" => self.strike += 1,
+            "a" => {
+                let href = attr(attrs, "href").unwrap_or("").to_string();
+                self.links.push((href, self.fragments.len()));
+            }
+            "img" => {
+                let alt = attr(attrs, "alt").unwrap_or("image");
+                self.push(&format!("[{alt}]"), console::Style::new().dim());
+            }
+            "tr" => {
+                self.flush();
+                self.first_cell = true;
+            }
+            _ => {}
+        }
+        if matches!(name, "td" | "th") {
+            if !self.first_cell {
+                self.push(" │ ", console::Style::new().dim());
+            }
+            self.first_cell = false;
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        if let Some(code) = &self.pre {
+            if name != "pre" {
+                return;
+            }
+            let prefix = self.prefix();
+            let style = console::Style::new().yellow();
+            let lines: Vec<String> = code
+                .trim_end_matches('\n')
+                .lines()
+                .map(|l| format!("{prefix}    {}", style.apply_to(l)))
+                .collect();
+            self.lines.extend(lines);
+            self.pre = None;
+            self.blank();
+            return;
+        }
+        match name {
+            "p" | "div" => {
+                self.flush();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.heading = None;
+                self.blank();
+            }
+            "ul" | "ol" => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            }
+            "li" | "tr" => self.flush(),
+            "blockquote" => {
+                self.flush();
+                if self.lines.last().is_some_and(|l| l.trim() == "│") {
+                    self.lines.pop();
+                }
+                self.quote = self.quote.saturating_sub(1);
+                self.blank();
+            }
+            "table" => {
+                self.flush();
+                self.blank();
+            }
+            "strong" | "b" | "th" => self.bold = self.bold.saturating_sub(1),
+            "em" | "i" => self.italic = self.italic.saturating_sub(1),
+            "code" => self.code = self.code.saturating_sub(1),
+            "s" | "del" | "strike" => self.strike = self.strike.saturating_sub(1),
+            "a" => {
+                if let Some((href, start)) = self.links.pop() {
+                    let text: String = self.fragments[start.min(self.fragments.len())..]
+                        .iter()
+                        .map(|(t, _)| t.as_str())
+                        .collect();
+                    if !href.is_empty() && text.trim() != href {
+                        self.push(&format!(" ({href})"), console::Style::new().dim());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        while self.lines.last().is_some_and(|l| l.trim().is_empty()) {
+            self.lines.pop();
+        }
+        self.lines.join("\n")
+    }
+}
+
+/// Renders HTML as formatted terminal text: headings, lists, checkboxes, code
+/// blocks, links and mentions, wrapped at `width` columns.
+pub fn render_terminal(html: &str, width: usize) -> String {
+    let mut renderer = TerminalRenderer::new(width);
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => renderer.text(&text),
+            Token::Open { name, attrs } => renderer.open(&name, &attrs),
+            Token::Close(name) => renderer.close(&name),
+        }
+    }
+    renderer.finish()
+}
+
+// ── Markdown conversion ──
+
+/// Converts HTML back into Markdown.
+struct MarkdownWriter {
+    blocks: Vec<String>,
+    buf: String,
+    lists: Vec<List>,
+    marker: Option<String>,
+    quote: usize,
+    pre: Option<(String, String)>,
+    links: Vec<String>,
+    table: Option<Vec<Vec<String>>>,
+    in_list: bool,
+}
+
+impl MarkdownWriter {
+    fn text(&mut self, text: &str) {
+        match &mut self.pre {
+            Some((_, code)) => code.push_str(text),
+            None => collapse_whitespace(text, &mut self.buf),
+        }
+    }
+
+    /// Ends the current block, prefixing its lines for lists and quotes.
+    fn flush(&mut self) {
+        let text = std::mem::take(&mut self.buf);
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        if let Some(table) = &mut self.table {
+            if let Some(row) = table.last_mut() {
+                row.push(text.replace('|', "\\|"));
+            }
+            return;
+        }
+
+        let quote = "> ".repeat(self.quote);
+        let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+        let (first, rest) = match self.marker.take() {
+            Some(m) => (
+                format!("{quote}{indent}{m} "),
+                format!("{quote}{indent}{}", " ".repeat(m.len() + 1)),
+            ),
+            None if !self.lists.is_empty() => {
+                (format!("{quote}{indent}  "), format!("{quote}{indent}  "))
+            }
+            None => (quote.clone(), quote),
+        };
+        let block: Vec<String> = text
+            .split('\n')
+            .enumerate()
+            .map(|(i, line)| {
+                let prefix = if i == 0 { &first } else { &rest };
+                format!("{prefix}{}", line.trim_start())
+            })
+            .collect();
+        self.push_block(block.join("\n"));
+    }
+
+    /// List items are kept together; everything else is separated by a blank line.
+    fn push_block(&mut self, block: String) {
+        match self.blocks.last_mut() {
+            Some(last) if self.in_list => {
+                last.push('\n');
+                last.push_str(&block);
+            }
+            _ => self.blocks.push(block),
+        }
+        self.in_list = !self.lists.is_empty();
+    }
+
+    fn open(&mut self, name: &str, attrs: &[(String, String)]) {
+        if let Some((lang, _)) = &mut self.pre {
+            if name == "code" {
+                *lang = attr(attrs, "class")
+                    .and_then(|c| c.strip_prefix("language-"))
+                    .unwrap_or("")
+                    .to_string();
+            }
+            return;
+        }
+        if let Some(label) = mention_label(name, attrs) {
+            self.buf.push_str(&label);
+            return;
+        }
+        match name {
+            "p" | "div" | "tr" => self.flush(),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                let level = name[1..].parse().unwrap_or(1);
+                self.buf.push_str(&"#".repeat(level));
+                self.buf.push(' ');
+            }
+            "ul" | "ol" => {
+                self.flush();
+                self.lists.push(list_for(name, attrs));
+            }
+            "li" => {
+                self.flush();
+                let bullet = self.lists.last_mut().map_or("-".to_string(), List::marker);
+                self.marker = Some(match task_marker(attrs) {
+                    Some(m) => format!("{bullet} {m}"),
+                    None => bullet,
+                });
+            }
+            "input" if attr(attrs, "type") == Some("checkbox") => {
+                let checked = attrs.iter().any(|(k, _)| k == "checked");
+                if let Some(m) = &mut self.marker
+                    && !m.ends_with(']')
+                {
+                    m.push_str(if checked { " [x]" } else { " [ ]" });
+                }
+            }
+            "blockquote" => {
+                self.flush();
+                self.quote += 1;
+            }
+            "pre" => {
+                self.flush();
+                self.pre = Some((String::new(), String::new()));
+            }
+            "br" => self.buf.push('\n'),
+            "hr" => {
+                self.flush();
+                self.push_block("---".to_string());
+            }
+            "strong" | "b" => self.buf.push_str("**"),
+            "em" | "i" => self.buf.push('_'),
+            "code" => self.buf.push('`'),
+            "s" | "del" | "strike" => self.buf.push_str("~~"),
+            "a" => {
+                self.links
+                    .push(attr(attrs, "href").unwrap_or("").to_string());
+                self.buf.push('[');
+            }
+            "img" => {
+                let alt = attr(attrs, "alt").unwrap_or("");
+                let src = attr(attrs, "src").unwrap_or("");
+                self.buf.push_str(&format!("![{alt}]({src})"));
+            }
+            "table" => {
+                self.flush();
+                self.table = Some(Vec::new());
+            }
+            _ => {}
+        }
+        if name == "tr"
+            && let Some(table) = &mut self.table
+        {
+            table.push(Vec::new());
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        if let Some((lang, code)) = &self.pre {
+            if name == "pre" {
+                let block = format!("```{lang}\n{}\n```", code.trim_end_matches('\n'));
+                self.pre = None;
+                let quote = "> ".repeat(self.quote);
+                let block = block
+                    .lines()
+                    .map(|l| format!("{quote}{l}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                self.push_block(block);
+            }
+            return;
+        }
+        match name {
+            "p" | "div" | "li" | "td" | "th" => self.flush(),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.flush(),
+            "ul" | "ol" => {
+                self.flush();
+                self.lists.pop();
+                self.in_list = !self.lists.is_empty();
+            }
+            "blockquote" => {
+                self.flush();
+                self.quote = self.quote.saturating_sub(1);
+            }
+            "strong" | "b" => self.buf.push_str("**"),
+            "em" | "i" => self.buf.push('_'),
+            "code" => self.buf.push('`'),
+            "s" | "del" | "strike" => self.buf.push_str("~~"),
+            "a" => {
+                let href = self.links.pop().unwrap_or_default();
+                self.buf.push_str(&format!("]({href})"));
+            }
+            "table" => {
+                if let Some(rows) = self.table.take() {
+                    let rows: Vec<&Vec<String>> = rows.iter().filter(|r| !r.is_empty()).collect();
+                    let mut lines = Vec::new();
+                    for (i, row) in rows.iter().enumerate() {
+                        lines.push(format!("| {} |", row.join(" | ")));
+                        if i == 0 {
+                            lines.push(format!("|{}", "---|".repeat(row.len())));
+                        }
+                    }
+                    if !lines.is_empty() {
+                        self.push_block(lines.join("\n"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Converts HTML (as stored in `description_html`) back into Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut writer = MarkdownWriter {
+        blocks: Vec::new(),
+        buf: String::new(),
+        lists: Vec::new(),
+        marker: None,
+        quote: 0,
+        pre: None,
+        links: Vec::new(),
+        table: None,
+        in_list: false,
+    };
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => writer.text(&text),
+            Token::Open { name, attrs } => writer.open(&name, &attrs),
+            Token::Close(name) => writer.close(&name),
+        }
+    }
+    writer.flush();
+    writer.blocks.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_tags_and_attrs() {
+        let tokens = tokenize(r#"<a href="https://x.io?a=1&amp;b=2" target=_blank>link</a><br/>"#);
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Open {
+                    name: "a".to_string(),
+                    attrs: vec![
+                        ("href".to_string(), "https://x.io?a=1&b=2".to_string()),
+                        ("target".to_string(), "_blank".to_string()),
+                    ],
+                },
+                Token::Text("link".to_string()),
+                Token::Close("a".to_string()),
+                Token::Open {
+                    name: "br".to_string(),
+                    attrs: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; &copy"),
+            "a <b> & 'c' &copy"
+        );
+    }
+
+    #[test]
+    fn test_to_plain_text() {
+        let html =
+            "<p>Done in <strong>#42</strong></p><ul><li>one</li><li>two</li></ul><p>a<br>b</p>";
+        assert_eq!(to_plain_text(html), "Done in #42\n- one\n- two\na\nb");
+    }
+
+    #[test]
+    fn test_to_plain_text_skips_comments() {
+        assert_eq!(to_plain_text("<!-- x --><p>hi</p>"), "hi");
+    }
+
+    #[test]
+    fn test_render_terminal_blocks() {
+        let html = "<h2>Steps</h2><p>Open the <strong>app</strong>.</p>\
+                    <ol><li><p>Log in</p></li><li><p>Click</p></li></ol>\
+                    <ul data-type=\"taskList\"><li data-type=\"taskItem\" data-checked=\"true\">\
+                    <p>done</p></li><li data-type=\"taskItem\" data-checked=\"false\"><p>todo</p></li></ul>";
+        assert_eq!(
+            render_terminal(html, 80),
+            "Steps\n\nOpen the app.\n\n1. Log in\n2. Click\n\n[x] done\n[ ] todo"
+        );
+    }
+
+    #[test]
+    fn test_render_terminal_wraps_list_items() {
+        let html = "<ul><li><p>one two three four five six</p></li></ul>";
+        assert_eq!(
+            render_terminal(html, 20),
+            "- one two three four\n  five six"
+        );
+    }
+
+    #[test]
+    fn test_render_terminal_code_links_mentions() {
+        let html = "<p>See <a href=\"https://x.io\">docs</a>, ping \
+                    <mention-component entity_identifier=\"u1\" label=\"alice\"></mention-component></p>\
+                    <pre><code>let a = 1;\nlet b = 2;</code></pre>";
+        assert_eq!(
+            render_terminal(html, 80),
+            "See docs (https://x.io), ping @alice\n\n    let a = 1;\n    let b = 2;"
+        );
+    }
+
+    #[test]
+    fn test_render_terminal_nested_lists_and_quotes() {
+        let html = "<ul><li>a<ul><li>b</li></ul></li></ul><blockquote><p>quoted</p></blockquote>";
+        assert_eq!(render_terminal(html, 80), "- a\n  - b\n\n│ quoted");
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let html = "<h2>Steps</h2><p>Use <code>plane</code> and <em>enjoy</em> \
+                    <a href=\"https://x.io\">it</a>.</p>\
+                    <ol><li><p>one</p></li><li><p>two</p></li></ol>\
+                    <ul data-type=\"taskList\"><li data-type=\"taskItem\" data-checked=\"true\">\
+                    <p>done</p></li></ul>\
+                    <pre><code class=\"language-rust\">fn main() {}\n</code></pre>";
+        assert_eq!(
+            to_markdown(html),
+            "## Steps\n\nUse `plane` and _enjoy_ [it](https://x.io).\n\n1. one\n2. two\n\n\
+             - [x] done\n\n```rust\nfn main() {}\n```"
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_round_trips_markdown_html() {
+        let md = "# Title\n\nSome **bold** text.\n\n- a\n- b\n\n> quoted";
+        assert_eq!(to_markdown(&crate::markdown::to_html(md)), md);
+    }
+
+    #[test]
+    fn test_to_markdown_table() {
+        let html = "<table><tr><th>A</th><th>B</th></tr><tr><td>1</td><td>2</td></tr></table>";
+        assert_eq!(to_markdown(html), "| A | B |\n|---|---|\n| 1 | 2 |");
+    }
+}
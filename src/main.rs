@@ -1,12 +1,25 @@
 mod client;
 mod commands;
+mod duration;
+mod html;
+mod manifest;
+mod markdown;
+mod rate_limit;
+mod resolve;
 mod settings;
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use client::Client;
-use commands::{IssuesCreateParams, IssuesListParams};
+use commands::{
+    ApplyParams, CyclesCreateParams, CyclesUpdateParams, DescriptionFormat, ExportFormat,
+    IntakeCreateParams, IntakeDecision, IntakeStatus, IssuesCreateParams, IssuesListParams,
+    IssuesUpdateParams, LabelsCreateParams, LabelsUpdateParams, ModuleStatus, ModulesCreateParams,
+    ModulesUpdateParams, Paging, ProjectFields, ProjectsCreateParams, ProjectsUpdateParams,
+    ReportGroup, Role, StateGroup, StatesCreateParams, StatesUpdateParams, WorklogReportParams,
+};
 use settings::{CliOverrides, Settings};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "plane", version, about = "CLI for Plane project management")]
@@ -36,6 +49,7 @@ struct Cli {
 }
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 enum Command {
     /// Manage projects
     Projects {
@@ -57,208 +71,1944 @@ enum Command {
         #[command(subcommand)]
         action: LabelsAction,
     },
-    /// Manage members
+    /// Manage project members
     Members {
         #[command(subcommand)]
         action: MembersAction,
     },
-}
+    /// Workspace-level commands
+    Workspace {
+        #[command(subcommand)]
+        action: WorkspaceAction,
+    },
+    /// Manage cycles (sprints)
+    Cycles {
+        #[command(subcommand)]
+        action: CyclesAction,
+    },
+    /// Manage modules
+    Modules {
+        #[command(subcommand)]
+        action: ModulesAction,
+    },
+    /// Triage the intake queue
+    Intake {
+        #[command(subcommand)]
+        action: IntakeAction,
+    },
+    /// Report logged time across issues
+    Worklog {
+        #[command(subcommand)]
+        action: WorklogCommand,
+    },
+    /// Export a project and all its issues to a JSON or tar archive
+    Export {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
 
-#[derive(Subcommand)]
-enum ProjectsAction {
-    /// List projects in the workspace
-    List,
+        /// Archive format
+        #[arg(long, value_enum, default_value = "json")]
+        format: ExportFormat,
+
+        /// Output path ("-" for stdout; defaults to <IDENTIFIER>-<DATE>.json or .tar)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Converge a project's states, labels, modules and members to a manifest
+    Apply {
+        /// Manifest file, YAML or JSON ("-" for stdin)
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Project ID, identifier or name (overrides `project` in the manifest)
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Print the plan without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Delete states, labels, modules and members missing from the manifest
+        #[arg(long)]
+        prune: bool,
+
+        /// Skip the confirmation prompt for deletions
+        #[arg(short, long)]
+        yes: bool,
+    },
 }
 
 #[derive(Subcommand)]
-enum StatesAction {
-    /// List states in a project
+enum IntakeAction {
+    /// List intake issues (pending by default)
     List {
-        /// Project ID
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Only show items with this status
+        #[arg(long, value_enum, default_value = "pending")]
+        status: IntakeStatus,
+
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Submit an issue to the intake queue
+    Create {
+        /// Project ID, identifier or name
         #[arg(short, long)]
         project: String,
+
+        /// Issue title
+        #[arg(short, long)]
+        title: String,
+
+        #[command(flatten)]
+        description: DescriptionArgs,
+
+        /// Priority
+        #[arg(long, value_enum)]
+        priority: Option<Priority>,
+    },
+    /// Accept an intake issue into the project
+    Accept {
+        #[command(flatten)]
+        target: IssueTarget,
+    },
+    /// Decline an intake issue
+    Decline {
+        #[command(flatten)]
+        target: IssueTarget,
+    },
+    /// Hide an intake issue until a date
+    Snooze {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Date to snooze until (YYYY-MM-DD)
+        #[arg(long)]
+        until: chrono::NaiveDate,
+    },
+    /// Mark an intake issue as a duplicate of an existing issue
+    MarkDuplicate {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Issue identifier (e.g. WEB-123) or ID it duplicates
+        #[arg(long)]
+        of: String,
     },
 }
 
 #[derive(Subcommand)]
-enum LabelsAction {
-    /// List labels in a project
-    List {
-        /// Project ID
+enum WorklogCommand {
+    /// Sum logged time per member, issue or project over a date range
+    Report {
+        /// Project ID, identifier or name (repeatable; defaults to all projects)
         #[arg(short, long)]
-        project: String,
+        project: Vec<String>,
+
+        /// First day to include (YYYY-MM-DD)
+        #[arg(long)]
+        from: Option<chrono::NaiveDate>,
+
+        /// Last day to include (YYYY-MM-DD)
+        #[arg(long)]
+        to: Option<chrono::NaiveDate>,
+
+        /// What to group logged time by
+        #[arg(long, value_enum, default_value = "member")]
+        by: ReportGroup,
+
+        /// Print CSV instead of a table
+        #[arg(long)]
+        csv: bool,
     },
 }
 
 #[derive(Subcommand)]
-enum MembersAction {
-    /// List members of a project
+enum ProjectsAction {
+    /// List projects in the workspace
     List {
-        /// Project ID
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Show project details
+    Get {
+        /// Project ID, identifier or name
+        project: String,
+    },
+    /// Create a project
+    Create {
+        /// Project name
+        #[arg(short, long)]
+        name: String,
+
+        /// Short identifier used in issue keys (e.g. WEB)
+        #[arg(short, long)]
+        identifier: String,
+
+        #[command(flatten)]
+        fields: ProjectFieldArgs,
+    },
+    /// Update a project (only the given fields are sent)
+    Update {
+        /// Project ID, identifier or name
+        project: String,
+
+        /// New name
+        #[arg(short, long)]
+        name: Option<String>,
+
+        /// New identifier
         #[arg(short, long)]
+        identifier: Option<String>,
+
+        #[command(flatten)]
+        fields: ProjectFieldArgs,
+    },
+    /// Archive a project
+    Archive {
+        /// Project ID, identifier or name
+        project: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Restore an archived project
+    Unarchive {
+        /// Project ID (archived projects can't be looked up by name)
         project: String,
     },
 }
 
+/// Project fields shared by `projects create` and `projects update`.
+#[derive(Args)]
+struct ProjectFieldArgs {
+    /// Project description
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Who can see and join the project
+    #[arg(long, value_enum)]
+    network: Option<Network>,
+
+    /// Project lead (workspace member display name, email or ID)
+    #[arg(long)]
+    lead: Option<String>,
+
+    /// Default assignee for new issues (display name, email or ID)
+    #[arg(long)]
+    default_assignee: Option<String>,
+
+    /// Enable or disable cycles (true/false)
+    #[arg(long)]
+    cycles: Option<bool>,
+
+    /// Enable or disable modules (true/false)
+    #[arg(long)]
+    modules: Option<bool>,
+
+    /// Enable or disable pages (true/false)
+    #[arg(long)]
+    pages: Option<bool>,
+}
+
+impl ProjectFieldArgs {
+    /// Resolves the lead and default assignee to workspace member IDs.
+    async fn resolve(
+        &self,
+        client: &Client,
+        workspace: &str,
+    ) -> Result<(Option<String>, Option<String>)> {
+        let lead = match &self.lead {
+            Some(l) => Some(resolve::workspace_member(client, workspace, l).await?),
+            None => None,
+        };
+        let assignee = match &self.default_assignee {
+            Some(a) => Some(resolve::workspace_member(client, workspace, a).await?),
+            None => None,
+        };
+        Ok((lead, assignee))
+    }
+
+    fn fields<'a>(
+        &'a self,
+        lead: Option<&'a str>,
+        default_assignee: Option<&'a str>,
+    ) -> ProjectFields<'a> {
+        ProjectFields {
+            description: self.description.as_deref(),
+            network: self.network.as_ref().map(Network::code),
+            lead,
+            default_assignee,
+            cycles: self.cycles,
+            modules: self.modules,
+            pages: self.pages,
+        }
+    }
+}
+
+#[derive(Clone, ValueEnum)]
+enum Network {
+    Secret,
+    Public,
+}
+
+impl Network {
+    fn code(&self) -> u8 {
+        match self {
+            Self::Secret => 0,
+            Self::Public => 2,
+        }
+    }
+}
+
 #[derive(Subcommand)]
-enum IssuesAction {
-    /// List issues in a project
+enum StatesAction {
+    /// List states in a project
     List {
-        /// Project ID
+        /// Project ID, identifier or name
         #[arg(short, long)]
         project: String,
 
-        /// Filter by state ID
-        #[arg(long)]
-        state: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Create a state
+    Create {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
 
-        /// Filter by assignee ID
-        #[arg(long)]
-        assignee: Option<String>,
+        /// State name
+        #[arg(short, long)]
+        name: String,
 
-        /// Results per page
-        #[arg(long, default_value = "50")]
-        per_page: u32,
+        /// Hex color (e.g. #3a86ff)
+        #[arg(long, value_parser = commands::parse_color)]
+        color: String,
 
-        /// Cursor for pagination (from previous response)
-        #[arg(long)]
-        cursor: Option<String>,
+        /// State group
+        #[arg(long, value_enum)]
+        group: StateGroup,
+
+        #[command(flatten)]
+        fields: StateFields,
     },
-    /// Get a single issue by ID
-    Get {
-        /// Project ID
+    /// Update a state (only the given fields are sent)
+    Update {
+        /// State name or ID
+        state: String,
+
+        /// Project ID, identifier or name
         #[arg(short, long)]
         project: String,
 
-        /// Issue ID
+        /// New name
         #[arg(short, long)]
-        id: String,
+        name: Option<String>,
+
+        /// Hex color (e.g. #3a86ff)
+        #[arg(long, value_parser = commands::parse_color)]
+        color: Option<String>,
+
+        /// State group
+        #[arg(long, value_enum)]
+        group: Option<StateGroup>,
+
+        #[command(flatten)]
+        fields: StateFields,
     },
-    /// Create a new issue
+    /// Delete a state
+    Delete {
+        /// State name or ID
+        state: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Move the state's issues to this state (name or ID) before deleting
+        #[arg(long)]
+        move_to: Option<String>,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+/// State fields shared by `states create` and `states update`.
+#[derive(Args)]
+struct StateFields {
+    /// Position in the state list; states are ordered by ascending sequence
+    #[arg(long)]
+    sequence: Option<f64>,
+
+    /// State description
+    #[arg(long)]
+    description: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum LabelsAction {
+    /// List labels in a project, with label groups shown as a tree
+    List {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+    },
+    /// Create a label
     Create {
-        /// Project ID
+        /// Project ID, identifier or name
         #[arg(short, long)]
         project: String,
 
-        /// Issue title
+        /// Label name
+        #[arg(short, long)]
+        name: String,
+
+        /// Hex color (e.g. #e63946)
+        #[arg(long, value_parser = commands::parse_color)]
+        color: Option<String>,
+
+        /// Parent label (name or ID) to nest this label under
         #[arg(long)]
-        title: String,
+        parent: Option<String>,
 
-        /// Issue description (HTML)
+        /// Label description
         #[arg(long)]
         description: Option<String>,
+    },
+    /// Update a label (only the given fields are sent)
+    Update {
+        /// Label name or ID
+        label: String,
 
-        /// State ID
-        #[arg(long)]
-        state: Option<String>,
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
 
-        /// Priority level
-        #[arg(long, value_enum)]
-        priority: Option<Priority>,
+        /// New name
+        #[arg(short, long)]
+        name: Option<String>,
 
-        /// Assignee member IDs (can be repeated)
+        /// Hex color (e.g. #e63946)
+        #[arg(long, value_parser = commands::parse_color)]
+        color: Option<String>,
+
+        /// Parent label (name or ID) to move this label under
+        #[arg(long, conflicts_with = "no_parent")]
+        parent: Option<String>,
+
+        /// Move the label to the top level
         #[arg(long)]
-        assignee: Vec<String>,
+        no_parent: bool,
 
-        /// Label IDs (can be repeated)
+        /// Label description
         #[arg(long)]
-        label: Vec<String>,
+        description: Option<String>,
     },
-}
+    /// Delete a label
+    Delete {
+        /// Label name or ID
+        label: String,
 
-#[derive(Clone, ValueEnum)]
-enum Priority {
-    None,
-    Urgent,
-    High,
-    Medium,
-    Low,
-}
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
 
-impl Priority {
-    fn as_str(&self) -> &'static str {
-        match self {
-            Self::None => "none",
-            Self::Urgent => "urgent",
-            Self::High => "high",
-            Self::Medium => "medium",
-            Self::Low => "low",
-        }
-    }
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
 }
 
-fn main() {
-    let cli = Cli::parse();
+#[derive(Subcommand)]
+enum MembersAction {
+    /// List members of a project with their email and role
+    List {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
 
-    if let Err(err) = run(cli) {
-        let style = console::Style::new().red().bold();
-        eprintln!("{} {err:#}", style.apply_to("error:"));
-        std::process::exit(1);
-    }
-}
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Add a workspace member to a project
+    Add {
+        /// Workspace member (display name, email or ID)
+        member: String,
 
-#[tokio::main]
-async fn run(cli: Cli) -> Result<()> {
-    let json_mode = cli.json;
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
 
-    let settings = Settings::load(CliOverrides {
-        api_key: cli.api_key,
-        base_url: cli.base_url,
-        workspace: cli.workspace,
-        timeout: cli.timeout,
-    })?;
+        /// Role in the project
+        #[arg(long, value_enum, default_value = "member")]
+        role: Role,
+    },
+    /// Remove a member from a project
+    Remove {
+        /// Project member (display name, email or ID)
+        member: String,
 
-    let workspace = settings.workspace.as_deref().context(
-        "workspace is required — set it via --workspace, PLANE_CLI_WORKSPACE, or config file",
-    )?;
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
 
-    let client = Client::new(&settings, json_mode)?;
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Change a member's role in a project
+    SetRole {
+        /// Project member (display name, email or ID)
+        member: String,
 
-    match cli.command {
-        Command::Projects { action } => match action {
-            ProjectsAction::List => {
-                commands::projects_list(&client, workspace, json_mode).await?;
-            }
-        },
-        Command::States { action } => match action {
-            StatesAction::List { project } => {
-                commands::states_list(&client, workspace, &project, json_mode).await?;
-            }
-        },
-        Command::Labels { action } => match action {
-            LabelsAction::List { project } => {
-                commands::labels_list(&client, workspace, &project, json_mode).await?;
-            }
-        },
-        Command::Members { action } => match action {
-            MembersAction::List { project } => {
-                commands::members_list(&client, workspace, &project, json_mode).await?;
-            }
-        },
-        Command::Issues { action } => match action {
-            IssuesAction::List {
-                project,
-                state,
-                assignee,
-                per_page,
-                cursor,
-            } => {
-                commands::issues_list(
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// New role
+        #[arg(long, value_enum)]
+        role: Role,
+    },
+}
+
+#[derive(Subcommand)]
+enum WorkspaceAction {
+    /// Manage workspace members
+    Members {
+        #[command(subcommand)]
+        action: WorkspaceMembersAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum WorkspaceMembersAction {
+    /// List everyone in the workspace, including people not in any project
+    List {
+        #[command(flatten)]
+        page: PageArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum CyclesAction {
+    /// List cycles in a project
+    List {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Show a cycle with its issue counts
+    Get {
+        /// Cycle ID or name
+        cycle: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+    },
+    /// Create a cycle
+    Create {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Cycle name
+        #[arg(long)]
+        name: String,
+
+        /// Cycle description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Start date (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: Option<String>,
+
+        /// End date (YYYY-MM-DD)
+        #[arg(long)]
+        end_date: Option<String>,
+    },
+    /// Update a cycle (only the given fields are sent)
+    Update {
+        /// Cycle ID or name
+        cycle: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// New name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// New description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Start date (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: Option<String>,
+
+        /// End date (YYYY-MM-DD)
+        #[arg(long)]
+        end_date: Option<String>,
+    },
+    /// Delete a cycle (its issues are kept)
+    Delete {
+        /// Cycle ID or name
+        cycle: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Add issues to a cycle
+    AddIssues {
+        /// Cycle ID or name
+        cycle: String,
+
+        /// Issue identifiers (e.g. WEB-123) or IDs
+        #[arg(required = true)]
+        issues: Vec<String>,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+    },
+    /// Remove an issue from a cycle
+    RemoveIssue {
+        /// Cycle ID or name
+        cycle: String,
+
+        /// Issue identifier (e.g. WEB-123) or ID
+        issue: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ModulesAction {
+    /// List modules in a project
+    List {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Show a module with its issue counts
+    Get {
+        /// Module ID or name
+        module: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+    },
+    /// Create a module
+    Create {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Module name
+        #[arg(long)]
+        name: String,
+
+        #[command(flatten)]
+        fields: ModuleFields,
+    },
+    /// Update a module (only the given fields are sent)
+    Update {
+        /// Module ID or name
+        module: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// New name
+        #[arg(long)]
+        name: Option<String>,
+
+        #[command(flatten)]
+        fields: ModuleFields,
+    },
+    /// Delete a module (its issues are kept)
+    Delete {
+        /// Module ID or name
+        module: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Add issues to a module
+    AddIssues {
+        /// Module ID or name
+        module: String,
+
+        /// Issue identifiers (e.g. WEB-123) or IDs
+        #[arg(required = true)]
+        issues: Vec<String>,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+    },
+    /// Remove an issue from a module
+    RemoveIssue {
+        /// Module ID or name
+        module: String,
+
+        /// Issue identifier (e.g. WEB-123) or ID
+        issue: String,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+    },
+}
+
+/// Module fields shared by `modules create` and `modules update`.
+#[derive(Args)]
+struct ModuleFields {
+    /// Module description
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Module status
+    #[arg(long, value_enum)]
+    status: Option<ModuleStatus>,
+
+    /// Lead (display name, email or ID)
+    #[arg(long)]
+    lead: Option<String>,
+
+    /// Member (display name, email or ID; repeatable)
+    #[arg(long)]
+    member: Vec<String>,
+
+    /// Start date (YYYY-MM-DD)
+    #[arg(long)]
+    start_date: Option<String>,
+
+    /// Target date (YYYY-MM-DD)
+    #[arg(long)]
+    target_date: Option<String>,
+}
+
+impl ModuleFields {
+    /// Resolves the lead and members to IDs.
+    async fn resolve(
+        &self,
+        client: &Client,
+        workspace: &str,
+        project: &str,
+    ) -> Result<(Option<String>, Vec<String>)> {
+        let lead = match &self.lead {
+            Some(l) => Some(resolve::member(client, workspace, project, l).await?),
+            None => None,
+        };
+        let members = resolve::members(client, workspace, project, &self.member).await?;
+        Ok((lead, members))
+    }
+}
+
+#[derive(Subcommand)]
+enum IssuesAction {
+    /// List issues in a project
+    List {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Filter by state (name or ID)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Filter by assignee (display name, email or ID)
+        #[arg(long)]
+        assignee: Option<String>,
+
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Get a single issue
+    Get {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// How to print the description: formatted text, or the raw
+        /// description alone as Markdown or HTML for piping
+        #[arg(long, value_enum, default_value_t = DescriptionFormat::Text)]
+        format: DescriptionFormat,
+    },
+    /// Create a new issue
+    Create {
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: String,
+
+        /// Issue title
+        #[arg(long)]
+        title: String,
+
+        #[command(flatten)]
+        description: DescriptionArgs,
+
+        /// State (name or ID)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Priority level
+        #[arg(long, value_enum)]
+        priority: Option<Priority>,
+
+        /// Assignees by display name, email or ID (can be repeated)
+        #[arg(long)]
+        assignee: Vec<String>,
+
+        /// Labels by name or ID (can be repeated)
+        #[arg(long)]
+        label: Vec<String>,
+
+        /// Parent issue (identifier like WEB-123, or ID)
+        #[arg(long)]
+        parent: Option<String>,
+    },
+    /// Update an existing issue (only the given fields are changed)
+    Update {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// New issue title
+        #[arg(long)]
+        title: Option<String>,
+
+        #[command(flatten)]
+        description: DescriptionArgs,
+
+        /// State (name or ID)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Priority level
+        #[arg(long, value_enum)]
+        priority: Option<Priority>,
+
+        /// Assignees by display name, email or ID (can be repeated, replaces current assignees)
+        #[arg(long)]
+        assignee: Vec<String>,
+
+        /// Labels by name or ID (can be repeated, replaces current labels)
+        #[arg(long)]
+        label: Vec<String>,
+
+        /// Start date (YYYY-MM-DD)
+        #[arg(long)]
+        start_date: Option<String>,
+
+        /// Target date (YYYY-MM-DD)
+        #[arg(long)]
+        target_date: Option<String>,
+
+        /// Parent issue (identifier like WEB-123, or ID)
+        #[arg(long)]
+        parent: Option<String>,
+    },
+    /// List the sub-issues of an issue
+    Children {
+        #[command(flatten)]
+        target: IssueTarget,
+    },
+    /// List an issue's relations to other issues
+    Relations {
+        #[command(flatten)]
+        target: IssueTarget,
+    },
+    /// Relate an issue to other issues
+    Relate {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        #[command(flatten)]
+        relations: RelationArgs,
+    },
+    /// Remove relations between issues
+    Unrelate {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        #[command(flatten)]
+        relations: RelationArgs,
+    },
+    /// Show the history of an issue: who changed which field, and when
+    Activity {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Only show activity since a date (2025-01-31) or a duration ago (30m, 12h, 7d, 2w)
+        #[arg(long)]
+        since: Option<String>,
+    },
+    /// Show the parent/child hierarchy of a project, or below one issue
+    Tree {
+        /// Issue identifier (e.g. WEB-123) or ID to show the subtree of
+        #[arg(value_name = "ISSUE", required_unless_present = "project")]
+        issue: Option<String>,
+
+        /// Project ID, identifier or name
+        #[arg(short, long)]
+        project: Option<String>,
+    },
+    /// Delete an issue permanently
+    Delete {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Archive an issue
+    Archive {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Restore an archived issue
+    Unarchive {
+        #[command(flatten)]
+        target: IssueTarget,
+    },
+    /// Manage issue comments
+    Comments {
+        #[command(subcommand)]
+        action: CommentsAction,
+    },
+    /// Manage links (external URLs) on an issue
+    Links {
+        #[command(subcommand)]
+        action: LinksAction,
+    },
+    /// Manage file attachments on an issue
+    Attachments {
+        #[command(subcommand)]
+        action: AttachmentsAction,
+    },
+    /// Log time spent on an issue
+    Worklog {
+        #[command(subcommand)]
+        action: WorklogAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum WorklogAction {
+    /// List time logged on an issue
+    List {
+        #[command(flatten)]
+        target: IssueTarget,
+    },
+    /// Log time on an issue
+    Add {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Time spent, e.g. 1h30m, 45m, 1.5h or 1d (8h)
+        #[arg(long, value_parser = duration::parse_minutes)]
+        duration: u64,
+
+        /// What the time was spent on
+        #[arg(long)]
+        description: Option<String>,
+    },
+    /// Delete a worklog entry
+    Delete {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Worklog ID
+        #[arg(short, long)]
+        worklog: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum AttachmentsAction {
+    /// List attachments on an issue
+    List {
+        #[command(flatten)]
+        target: IssueTarget,
+    },
+    /// Upload a file to an issue
+    Upload {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// File to upload
+        #[arg(short, long)]
+        file: PathBuf,
+    },
+    /// Download an attachment
+    Download {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Attachment ID or file name
+        #[arg(short, long)]
+        attachment: String,
+
+        /// Where to save the file (a file or directory; defaults to the
+        /// attachment's name in the current directory)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Delete an attachment
+    Delete {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Attachment ID or file name
+        #[arg(short, long)]
+        attachment: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum LinksAction {
+    /// List links on an issue
+    List {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Attach a URL to an issue
+    Add {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// URL to attach
+        #[arg(long)]
+        url: String,
+
+        /// Link title
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Remove a link from an issue
+    Remove {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Link ID or URL
+        #[arg(short, long)]
+        link: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum CommentsAction {
+    /// List comments on an issue
+    List {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Add a comment to an issue
+    Add {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Comment text
+        #[arg(long)]
+        body: Option<String>,
+
+        /// Read the comment from a file ("-" for stdin)
+        #[arg(long, conflicts_with = "body")]
+        file: Option<PathBuf>,
+    },
+    /// Edit an existing comment
+    Edit {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Comment ID
+        #[arg(short, long)]
+        comment: String,
+
+        /// New comment text
+        #[arg(long)]
+        body: Option<String>,
+
+        /// Read the comment from a file ("-" for stdin)
+        #[arg(long, conflicts_with = "body")]
+        file: Option<PathBuf>,
+    },
+    /// Delete a comment
+    Delete {
+        #[command(flatten)]
+        target: IssueTarget,
+
+        /// Comment ID
+        #[arg(short, long)]
+        comment: String,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+/// Pagination options shared by the list commands.
+#[derive(Args)]
+struct PageArgs {
+    /// Results per page
+    #[arg(long)]
+    per_page: Option<u32>,
+
+    /// Cursor for pagination (from previous response)
+    #[arg(long)]
+    cursor: Option<String>,
+
+    /// Fetch all pages, following the next cursor until exhausted
+    #[arg(long)]
+    all: bool,
+
+    /// Fetch pages until this many results have been returned
+    #[arg(long)]
+    limit: Option<usize>,
+}
+
+impl PageArgs {
+    fn paging(&self) -> Paging<'_> {
+        Paging {
+            per_page: self.per_page,
+            cursor: self.cursor.as_deref(),
+            all: self.all,
+            limit: self.limit,
+        }
+    }
+}
+
+/// Issue description given as HTML, Markdown, or a file.
+#[derive(Args)]
+#[group(multiple = false)]
+struct DescriptionArgs {
+    /// Issue description (HTML)
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Issue description (Markdown)
+    #[arg(long)]
+    description_md: Option<String>,
+
+    /// Read the description from a file ("-" for stdin). `.html` files are sent
+    /// as-is; anything else (`.md`, `.txt`, stdin) is converted from Markdown
+    #[arg(long)]
+    description_file: Option<PathBuf>,
+}
+
+impl DescriptionArgs {
+    /// Returns the description as HTML, if one was given.
+    fn html(&self) -> Result<Option<String>> {
+        if let Some(html) = &self.description {
+            return Ok(Some(html.clone()));
+        }
+        if let Some(md) = &self.description_md {
+            return Ok(Some(markdown::to_html(md)));
+        }
+        let Some(path) = &self.description_file else {
+            return Ok(None);
+        };
+        let content = commands::read_text(None, Some(path), "description")?;
+        let is_html = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("html") || e.eq_ignore_ascii_case("htm"));
+        if is_html {
+            Ok(Some(content))
+        } else {
+            Ok(Some(markdown::to_html(&content)))
+        }
+    }
+}
+
+/// Related issues by relation type, each given as an identifier (WEB-123) or ID.
+#[derive(Args)]
+struct RelationArgs {
+    /// Issues this issue blocks (repeatable)
+    #[arg(long)]
+    blocks: Vec<String>,
+
+    /// Issues blocking this issue (repeatable)
+    #[arg(long)]
+    blocked_by: Vec<String>,
+
+    /// Issues this issue duplicates (repeatable)
+    #[arg(long)]
+    duplicate_of: Vec<String>,
+
+    /// Issues related to this issue (repeatable)
+    #[arg(long)]
+    relates_to: Vec<String>,
+}
+
+impl RelationArgs {
+    /// Resolves the related issues, paired with Plane's relation type names.
+    async fn resolve(
+        &self,
+        client: &Client,
+        workspace: &str,
+        project: &str,
+    ) -> Result<Vec<(&'static str, Vec<String>)>> {
+        let mut relations = Vec::new();
+        for (relation_type, references) in [
+            ("blocking", &self.blocks),
+            ("blocked_by", &self.blocked_by),
+            ("duplicate", &self.duplicate_of),
+            ("relates_to", &self.relates_to),
+        ] {
+            let ids = related_issue_ids(client, workspace, project, references).await?;
+            relations.push((relation_type, ids));
+        }
+        Ok(relations)
+    }
+}
+
+/// Identifies a single issue, either by identifier (`WEB-123`) or by ID with `--project`.
+#[derive(Args)]
+struct IssueTarget {
+    /// Issue identifier (e.g. WEB-123) or ID
+    #[arg(value_name = "ISSUE", required_unless_present = "id")]
+    issue: Option<String>,
+
+    /// Project ID, identifier or name (not needed when the issue is given as an identifier)
+    #[arg(short, long)]
+    project: Option<String>,
+
+    /// Issue ID or identifier (alternative to the positional argument)
+    #[arg(short, long, conflicts_with = "issue")]
+    id: Option<String>,
+}
+
+impl IssueTarget {
+    async fn resolve(&self, client: &Client, workspace: &str) -> Result<resolve::ResolvedIssue> {
+        let reference = self
+            .issue
+            .as_deref()
+            .or(self.id.as_deref())
+            .context("issue is required")?;
+        resolve::issue(client, workspace, self.project.as_deref(), reference).await
+    }
+}
+
+/// Resolves issue identifiers or IDs to IDs within an already resolved project.
+async fn issue_ids(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    references: &[String],
+) -> Result<Vec<String>> {
+    let mut ids = Vec::with_capacity(references.len());
+    for reference in references {
+        let issue = resolve::issue(client, workspace, Some(project), reference).await?;
+        ids.push(issue.id);
+    }
+    Ok(ids)
+}
+
+/// Like [`issue_ids`], but identifiers may point into other projects, since
+/// relations can cross projects. Plain IDs are taken to be in `project`.
+async fn related_issue_ids(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    references: &[String],
+) -> Result<Vec<String>> {
+    let mut ids = Vec::with_capacity(references.len());
+    for reference in references {
+        let scope = (!resolve::is_identifier(&reference.to_uppercase())).then_some(project);
+        let issue = resolve::issue(client, workspace, scope, reference).await?;
+        ids.push(issue.id);
+    }
+    Ok(ids)
+}
+
+#[derive(Clone, ValueEnum)]
+enum Priority {
+    None,
+    Urgent,
+    High,
+    Medium,
+    Low,
+}
+
+impl Priority {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Urgent => "urgent",
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(err) = run(cli) {
+        let style = console::Style::new().red().bold();
+        eprintln!("{} {err:#}", style.apply_to("error:"));
+        std::process::exit(1);
+    }
+}
+
+#[tokio::main]
+async fn run(cli: Cli) -> Result<()> {
+    let json_mode = cli.json;
+
+    let settings = Settings::load(CliOverrides {
+        api_key: cli.api_key,
+        base_url: cli.base_url,
+        workspace: cli.workspace,
+        timeout: cli.timeout,
+    })?;
+
+    let workspace = settings.workspace.as_deref().context(
+        "workspace is required — set it via --workspace, PLANE_CLI_WORKSPACE, or config file",
+    )?;
+
+    let client = Client::new(&settings, json_mode)?;
+
+    match cli.command {
+        Command::Projects { action } => match action {
+            ProjectsAction::List { page } => {
+                commands::projects_list(&client, workspace, &page.paging(), json_mode).await?;
+            }
+            ProjectsAction::Get { project } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::projects_get(&client, workspace, &project, json_mode).await?;
+            }
+            ProjectsAction::Create {
+                name,
+                identifier,
+                fields,
+            } => {
+                let (lead, assignee) = fields.resolve(&client, workspace).await?;
+                commands::projects_create(
+                    &client,
+                    workspace,
+                    &ProjectsCreateParams {
+                        name: &name,
+                        identifier: &identifier,
+                        fields: fields.fields(lead.as_deref(), assignee.as_deref()),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            ProjectsAction::Update {
+                project,
+                name,
+                identifier,
+                fields,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let (lead, assignee) = fields.resolve(&client, workspace).await?;
+                commands::projects_update(
+                    &client,
+                    workspace,
+                    &ProjectsUpdateParams {
+                        id: &project,
+                        name: name.as_deref(),
+                        identifier: identifier.as_deref(),
+                        fields: fields.fields(lead.as_deref(), assignee.as_deref()),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            ProjectsAction::Archive { project, yes } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::projects_archive(&client, workspace, &project, yes, json_mode).await?;
+            }
+            ProjectsAction::Unarchive { project } => {
+                if !resolve::is_uuid(&project) {
+                    anyhow::bail!(
+                        "archived projects aren't listed by the API — pass the project ID to unarchive '{project}'"
+                    );
+                }
+                commands::projects_unarchive(&client, workspace, &project, json_mode).await?;
+            }
+        },
+        Command::States { action } => match action {
+            StatesAction::List { project, page } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::states_list(&client, workspace, &project, &page.paging(), json_mode)
+                    .await?;
+            }
+            StatesAction::Create {
+                project,
+                name,
+                color,
+                group,
+                fields,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::states_create(
+                    &client,
+                    workspace,
+                    &StatesCreateParams {
+                        project: &project,
+                        name: &name,
+                        color: &color,
+                        group: group.as_str(),
+                        sequence: fields.sequence,
+                        description: fields.description.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            StatesAction::Update {
+                state,
+                project,
+                name,
+                color,
+                group,
+                fields,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let state = resolve::state(&client, workspace, &project, &state).await?;
+                commands::states_update(
+                    &client,
+                    workspace,
+                    &StatesUpdateParams {
+                        project: &project,
+                        id: &state,
+                        name: name.as_deref(),
+                        color: color.as_deref(),
+                        group: group.as_ref().map(StateGroup::as_str),
+                        sequence: fields.sequence,
+                        description: fields.description.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            StatesAction::Delete {
+                state,
+                project,
+                move_to,
+                yes,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let state = resolve::state(&client, workspace, &project, &state).await?;
+                let move_to = match move_to {
+                    Some(s) => Some(resolve::state(&client, workspace, &project, &s).await?),
+                    None => None,
+                };
+                commands::states_delete(
+                    &client,
+                    workspace,
+                    &project,
+                    &state,
+                    move_to.as_deref(),
+                    yes,
+                    json_mode,
+                )
+                .await?;
+            }
+        },
+        Command::Labels { action } => match action {
+            LabelsAction::List { project } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::labels_list(&client, workspace, &project, json_mode).await?;
+            }
+            LabelsAction::Create {
+                project,
+                name,
+                color,
+                parent,
+                description,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let parent = match parent {
+                    Some(p) => Some(resolve::label(&client, workspace, &project, &p).await?),
+                    None => None,
+                };
+                commands::labels_create(
+                    &client,
+                    workspace,
+                    &LabelsCreateParams {
+                        project: &project,
+                        name: &name,
+                        color: color.as_deref(),
+                        parent: parent.as_deref(),
+                        description: description.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            LabelsAction::Update {
+                label,
+                project,
+                name,
+                color,
+                parent,
+                no_parent,
+                description,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let label = resolve::label(&client, workspace, &project, &label).await?;
+                let parent = match parent {
+                    Some(p) => Some(resolve::label(&client, workspace, &project, &p).await?),
+                    None => None,
+                };
+                commands::labels_update(
+                    &client,
+                    workspace,
+                    &LabelsUpdateParams {
+                        project: &project,
+                        id: &label,
+                        name: name.as_deref(),
+                        color: color.as_deref(),
+                        parent: if no_parent {
+                            Some(None)
+                        } else {
+                            parent.as_deref().map(Some)
+                        },
+                        description: description.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            LabelsAction::Delete {
+                label,
+                project,
+                yes,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let label = resolve::label(&client, workspace, &project, &label).await?;
+                commands::labels_delete(&client, workspace, &project, &label, yes, json_mode)
+                    .await?;
+            }
+        },
+        Command::Members { action } => match action {
+            MembersAction::List { project, page } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::members_list(&client, workspace, &project, &page.paging(), json_mode)
+                    .await?;
+            }
+            MembersAction::Add {
+                member,
+                project,
+                role,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let member = resolve::workspace_member(&client, workspace, &member).await?;
+                commands::members_add(
+                    &client,
+                    workspace,
+                    &project,
+                    &member,
+                    role.code(),
+                    json_mode,
+                )
+                .await?;
+            }
+            MembersAction::Remove {
+                member,
+                project,
+                yes,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let member = resolve::membership(&client, workspace, &project, &member).await?;
+                commands::members_remove(&client, workspace, &project, &member, yes, json_mode)
+                    .await?;
+            }
+            MembersAction::SetRole {
+                member,
+                project,
+                role,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let member = resolve::membership(&client, workspace, &project, &member).await?;
+                commands::members_set_role(
+                    &client,
+                    workspace,
+                    &project,
+                    &member,
+                    role.code(),
+                    json_mode,
+                )
+                .await?;
+            }
+        },
+        Command::Workspace { action } => match action {
+            WorkspaceAction::Members { action } => match action {
+                WorkspaceMembersAction::List { page } => {
+                    commands::workspace_members_list(&client, workspace, &page.paging(), json_mode)
+                        .await?;
+                }
+            },
+        },
+        Command::Cycles { action } => match action {
+            CyclesAction::List { project, page } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::cycles_list(&client, workspace, &project, &page.paging(), json_mode)
+                    .await?;
+            }
+            CyclesAction::Get { cycle, project } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let cycle = resolve::cycle(&client, workspace, &project, &cycle).await?;
+                commands::cycles_get(&client, workspace, &project, &cycle, json_mode).await?;
+            }
+            CyclesAction::Create {
+                project,
+                name,
+                description,
+                start_date,
+                end_date,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::cycles_create(
+                    &client,
+                    workspace,
+                    &CyclesCreateParams {
+                        project: &project,
+                        name: &name,
+                        description: description.as_deref(),
+                        start_date: start_date.as_deref(),
+                        end_date: end_date.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            CyclesAction::Update {
+                cycle,
+                project,
+                name,
+                description,
+                start_date,
+                end_date,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let cycle = resolve::cycle(&client, workspace, &project, &cycle).await?;
+                commands::cycles_update(
+                    &client,
+                    workspace,
+                    &CyclesUpdateParams {
+                        project: &project,
+                        id: &cycle,
+                        name: name.as_deref(),
+                        description: description.as_deref(),
+                        start_date: start_date.as_deref(),
+                        end_date: end_date.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            CyclesAction::Delete {
+                cycle,
+                project,
+                yes,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let cycle = resolve::cycle(&client, workspace, &project, &cycle).await?;
+                commands::cycles_delete(&client, workspace, &project, &cycle, yes, json_mode)
+                    .await?;
+            }
+            CyclesAction::AddIssues {
+                cycle,
+                issues,
+                project,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let cycle = resolve::cycle(&client, workspace, &project, &cycle).await?;
+                let issues = issue_ids(&client, workspace, &project, &issues).await?;
+                commands::cycles_add_issues(
+                    &client, workspace, &project, &cycle, &issues, json_mode,
+                )
+                .await?;
+            }
+            CyclesAction::RemoveIssue {
+                cycle,
+                issue,
+                project,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let cycle = resolve::cycle(&client, workspace, &project, &cycle).await?;
+                let issue = resolve::issue(&client, workspace, Some(&project), &issue).await?;
+                commands::cycles_remove_issue(
+                    &client, workspace, &project, &cycle, &issue.id, json_mode,
+                )
+                .await?;
+            }
+        },
+        Command::Modules { action } => match action {
+            ModulesAction::List { project, page } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::modules_list(&client, workspace, &project, &page.paging(), json_mode)
+                    .await?;
+            }
+            ModulesAction::Get { module, project } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let module = resolve::module(&client, workspace, &project, &module).await?;
+                commands::modules_get(&client, workspace, &project, &module, json_mode).await?;
+            }
+            ModulesAction::Create {
+                project,
+                name,
+                fields,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let (lead, members) = fields.resolve(&client, workspace, &project).await?;
+                commands::modules_create(
+                    &client,
+                    workspace,
+                    &ModulesCreateParams {
+                        project: &project,
+                        name: &name,
+                        description: fields.description.as_deref(),
+                        status: fields.status.as_ref().map(ModuleStatus::as_str),
+                        lead: lead.as_deref(),
+                        members: &members,
+                        start_date: fields.start_date.as_deref(),
+                        target_date: fields.target_date.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            ModulesAction::Update {
+                module,
+                project,
+                name,
+                fields,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let module = resolve::module(&client, workspace, &project, &module).await?;
+                let (lead, members) = fields.resolve(&client, workspace, &project).await?;
+                commands::modules_update(
+                    &client,
+                    workspace,
+                    &ModulesUpdateParams {
+                        project: &project,
+                        id: &module,
+                        name: name.as_deref(),
+                        description: fields.description.as_deref(),
+                        status: fields.status.as_ref().map(ModuleStatus::as_str),
+                        lead: lead.as_deref(),
+                        members: &members,
+                        start_date: fields.start_date.as_deref(),
+                        target_date: fields.target_date.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            ModulesAction::Delete {
+                module,
+                project,
+                yes,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let module = resolve::module(&client, workspace, &project, &module).await?;
+                commands::modules_delete(&client, workspace, &project, &module, yes, json_mode)
+                    .await?;
+            }
+            ModulesAction::AddIssues {
+                module,
+                issues,
+                project,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let module = resolve::module(&client, workspace, &project, &module).await?;
+                let issues = issue_ids(&client, workspace, &project, &issues).await?;
+                commands::modules_add_issues(
+                    &client, workspace, &project, &module, &issues, json_mode,
+                )
+                .await?;
+            }
+            ModulesAction::RemoveIssue {
+                module,
+                issue,
+                project,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let module = resolve::module(&client, workspace, &project, &module).await?;
+                let issue = resolve::issue(&client, workspace, Some(&project), &issue).await?;
+                commands::modules_remove_issue(
+                    &client, workspace, &project, &module, &issue.id, json_mode,
+                )
+                .await?;
+            }
+        },
+        Command::Issues { action } => match action {
+            IssuesAction::List {
+                project,
+                state,
+                assignee,
+                page,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                let state = match state {
+                    Some(s) => Some(resolve::state(&client, workspace, &project, &s).await?),
+                    None => None,
+                };
+                let assignee = match assignee {
+                    Some(a) => Some(resolve::member(&client, workspace, &project, &a).await?),
+                    None => None,
+                };
+                commands::issues_list(
                     &client,
                     workspace,
                     &IssuesListParams {
                         project: &project,
                         state: state.as_deref(),
                         assignee: assignee.as_deref(),
-                        per_page,
-                        cursor: cursor.as_deref(),
+                        paging: Paging {
+                            per_page: page.per_page.or(Some(50)),
+                            ..page.paging()
+                        },
                     },
                     json_mode,
                 )
                 .await?;
             }
-            IssuesAction::Get { project, id } => {
-                commands::issues_get(&client, workspace, &project, &id, json_mode).await?;
+            IssuesAction::Get { target, format } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::issues_get(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    format,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Children { target } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::issues_children(&client, workspace, &issue.project, &issue.id, json_mode)
+                    .await?;
+            }
+            IssuesAction::Relations { target } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::issues_relations(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Relate { target, relations } => {
+                let issue = target.resolve(&client, workspace).await?;
+                let relations = relations
+                    .resolve(&client, workspace, &issue.project)
+                    .await?;
+                commands::issues_relate(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    &relations,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Unrelate { target, relations } => {
+                let issue = target.resolve(&client, workspace).await?;
+                let relations = relations
+                    .resolve(&client, workspace, &issue.project)
+                    .await?;
+                commands::issues_unrelate(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    &relations,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Activity { target, since } => {
+                let since = since.as_deref().map(commands::parse_since).transpose()?;
+                let issue = target.resolve(&client, workspace).await?;
+                commands::issues_activity(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    since,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Tree { issue, project } => {
+                let (project, root) = match issue {
+                    Some(reference) => {
+                        let issue =
+                            resolve::issue(&client, workspace, project.as_deref(), &reference)
+                                .await?;
+                        (issue.project, Some(issue.id))
+                    }
+                    None => {
+                        let project = project.context("project is required")?;
+                        (resolve::project(&client, workspace, &project).await?, None)
+                    }
+                };
+                commands::issues_tree(&client, workspace, &project, root.as_deref(), json_mode)
+                    .await?;
             }
             IssuesAction::Create {
                 project,
@@ -268,7 +2018,24 @@ async fn run(cli: Cli) -> Result<()> {
                 priority,
                 assignee,
                 label,
+                parent,
             } => {
+                let description = description.html()?;
+                let project = resolve::project(&client, workspace, &project).await?;
+                let state = match state {
+                    Some(s) => Some(resolve::state(&client, workspace, &project, &s).await?),
+                    None => None,
+                };
+                let assignee = resolve::members(&client, workspace, &project, &assignee).await?;
+                let label = resolve::labels(&client, workspace, &project, &label).await?;
+                let parent = match parent {
+                    Some(p) => Some(
+                        resolve::issue(&client, workspace, Some(&project), &p)
+                            .await?
+                            .id,
+                    ),
+                    None => None,
+                };
                 commands::issues_create(
                     &client,
                     workspace,
@@ -280,11 +2047,471 @@ async fn run(cli: Cli) -> Result<()> {
                         priority: priority.as_ref().map(Priority::as_str),
                         assignees: &assignee,
                         labels: &label,
+                        parent: parent.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Update {
+                target,
+                title,
+                description,
+                state,
+                priority,
+                assignee,
+                label,
+                start_date,
+                target_date,
+                parent,
+            } => {
+                let description = description.html()?;
+                let issue = target.resolve(&client, workspace).await?;
+                let state = match state {
+                    Some(s) => Some(resolve::state(&client, workspace, &issue.project, &s).await?),
+                    None => None,
+                };
+                let assignee =
+                    resolve::members(&client, workspace, &issue.project, &assignee).await?;
+                let label = resolve::labels(&client, workspace, &issue.project, &label).await?;
+                let parent = match parent {
+                    Some(p) => Some(
+                        resolve::issue(&client, workspace, Some(&issue.project), &p)
+                            .await?
+                            .id,
+                    ),
+                    None => None,
+                };
+                commands::issues_update(
+                    &client,
+                    workspace,
+                    &IssuesUpdateParams {
+                        project: &issue.project,
+                        id: &issue.id,
+                        title: title.as_deref(),
+                        description: description.as_deref(),
+                        state: state.as_deref(),
+                        priority: priority.as_ref().map(Priority::as_str),
+                        assignees: &assignee,
+                        labels: &label,
+                        start_date: start_date.as_deref(),
+                        target_date: target_date.as_deref(),
+                        parent: parent.as_deref(),
+                    },
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Delete { target, yes } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::issues_delete(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    yes,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Archive { target, yes } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::issues_archive(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    yes,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Unarchive { target } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::issues_unarchive(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    json_mode,
+                )
+                .await?;
+            }
+            IssuesAction::Comments { action } => match action {
+                CommentsAction::List { target, page } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::comments_list(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &page.paging(),
+                        json_mode,
+                    )
+                    .await?;
+                }
+                CommentsAction::Add { target, body, file } => {
+                    let text = commands::read_text(body.as_deref(), file.as_deref(), "comment")?;
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::comments_add(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &markdown::to_html(&text),
+                        json_mode,
+                    )
+                    .await?;
+                }
+                CommentsAction::Edit {
+                    target,
+                    comment,
+                    body,
+                    file,
+                } => {
+                    let text = commands::read_text(body.as_deref(), file.as_deref(), "comment")?;
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::comments_edit(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &comment,
+                        &markdown::to_html(&text),
+                        json_mode,
+                    )
+                    .await?;
+                }
+                CommentsAction::Delete {
+                    target,
+                    comment,
+                    yes,
+                } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::comments_delete(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &comment,
+                        yes,
+                        json_mode,
+                    )
+                    .await?;
+                }
+            },
+            IssuesAction::Links { action } => match action {
+                LinksAction::List { target, page } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::links_list(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &page.paging(),
+                        json_mode,
+                    )
+                    .await?;
+                }
+                LinksAction::Add { target, url, title } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::links_add(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &url,
+                        title.as_deref(),
+                        json_mode,
+                    )
+                    .await?;
+                }
+                LinksAction::Remove { target, link } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::links_remove(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &link,
+                        json_mode,
+                    )
+                    .await?;
+                }
+            },
+            IssuesAction::Attachments { action } => match action {
+                AttachmentsAction::List { target } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::attachments_list(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        json_mode,
+                    )
+                    .await?;
+                }
+                AttachmentsAction::Upload { target, file } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::attachments_upload(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &file,
+                        json_mode,
+                    )
+                    .await?;
+                }
+                AttachmentsAction::Download {
+                    target,
+                    attachment,
+                    output,
+                } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::attachments_download(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &attachment,
+                        output.as_deref(),
+                        json_mode,
+                    )
+                    .await?;
+                }
+                AttachmentsAction::Delete {
+                    target,
+                    attachment,
+                    yes,
+                } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::attachments_delete(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &attachment,
+                        yes,
+                        json_mode,
+                    )
+                    .await?;
+                }
+            },
+            IssuesAction::Worklog { action } => match action {
+                WorklogAction::List { target } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::worklogs_list(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        json_mode,
+                    )
+                    .await?;
+                }
+                WorklogAction::Add {
+                    target,
+                    duration,
+                    description,
+                } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::worklogs_add(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        duration,
+                        description.as_deref(),
+                        json_mode,
+                    )
+                    .await?;
+                }
+                WorklogAction::Delete {
+                    target,
+                    worklog,
+                    yes,
+                } => {
+                    let issue = target.resolve(&client, workspace).await?;
+                    commands::worklogs_delete(
+                        &client,
+                        workspace,
+                        &issue.project,
+                        &issue.id,
+                        &worklog,
+                        yes,
+                        json_mode,
+                    )
+                    .await?;
+                }
+            },
+        },
+        Command::Intake { action } => match action {
+            IntakeAction::List {
+                project,
+                status,
+                page,
+            } => {
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::intake_list(
+                    &client,
+                    workspace,
+                    &project,
+                    status,
+                    &page.paging(),
+                    json_mode,
+                )
+                .await?;
+            }
+            IntakeAction::Create {
+                project,
+                title,
+                description,
+                priority,
+            } => {
+                let description = description.html()?;
+                let project = resolve::project(&client, workspace, &project).await?;
+                commands::intake_create(
+                    &client,
+                    workspace,
+                    &IntakeCreateParams {
+                        project: &project,
+                        title: &title,
+                        description: description.as_deref(),
+                        priority: priority.as_ref().map(Priority::as_str),
                     },
                     json_mode,
                 )
                 .await?;
             }
+            IntakeAction::Accept { target } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::intake_decide(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    &IntakeDecision::Accept,
+                    json_mode,
+                )
+                .await?;
+            }
+            IntakeAction::Decline { target } => {
+                let issue = target.resolve(&client, workspace).await?;
+                commands::intake_decide(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    &IntakeDecision::Decline,
+                    json_mode,
+                )
+                .await?;
+            }
+            IntakeAction::Snooze { target, until } => {
+                let until = until
+                    .and_time(chrono::NaiveTime::MIN)
+                    .and_local_timezone(chrono::Local)
+                    .earliest()
+                    .context("invalid --until date in the local time zone")?
+                    .to_rfc3339();
+                let issue = target.resolve(&client, workspace).await?;
+                commands::intake_decide(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    &IntakeDecision::Snooze(&until),
+                    json_mode,
+                )
+                .await?;
+            }
+            IntakeAction::MarkDuplicate { target, of } => {
+                let issue = target.resolve(&client, workspace).await?;
+                let original = resolve::issue(&client, workspace, Some(&issue.project), &of)
+                    .await?
+                    .id;
+                commands::intake_decide(
+                    &client,
+                    workspace,
+                    &issue.project,
+                    &issue.id,
+                    &IntakeDecision::Duplicate(&original),
+                    json_mode,
+                )
+                .await?;
+            }
+        },
+        Command::Export {
+            project,
+            format,
+            output,
+        } => {
+            let project = resolve::project(&client, workspace, &project).await?;
+            commands::export(
+                &client,
+                workspace,
+                &project,
+                format,
+                output.as_deref(),
+                json_mode,
+            )
+            .await?;
+        }
+        Command::Apply {
+            file,
+            project,
+            dry_run,
+            prune,
+            yes,
+        } => {
+            let text = commands::read_text(None, Some(&file), "manifest")?;
+            let manifest = manifest::Manifest::parse(&text)
+                .with_context(|| format!("failed to load {}", file.display()))?;
+            let project = project
+                .or_else(|| manifest.project.clone())
+                .context("no project — set `project` in the manifest or pass --project")?;
+            let project = resolve::project(&client, workspace, &project).await?;
+            commands::apply(
+                &client,
+                workspace,
+                &ApplyParams {
+                    project: &project,
+                    manifest: &manifest,
+                    dry_run,
+                    prune,
+                    yes,
+                },
+                json_mode,
+            )
+            .await?;
+        }
+        Command::Worklog { action } => match action {
+            WorklogCommand::Report {
+                project,
+                from,
+                to,
+                by,
+                csv,
+            } => {
+                if let (Some(from), Some(to)) = (from, to)
+                    && from > to
+                {
+                    anyhow::bail!("--from {from} is after --to {to}");
+                }
+                let mut projects = Vec::new();
+                for value in &project {
+                    projects.push(resolve::project(&client, workspace, value).await?);
+                }
+                let params = WorklogReportParams {
+                    projects: &projects,
+                    from,
+                    to,
+                    by,
+                    csv,
+                };
+                commands::worklog_report(&client, workspace, &params, json_mode).await?;
+            }
         },
     }
 
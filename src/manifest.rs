@@ -0,0 +1,829 @@
+//! Declarative project setup for `plane apply`: the manifest format and the
+//! plan that converges a live project to it.
+
+use anyhow::{Context, Result, anyhow, bail};
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::{Map, Value, json};
+use std::collections::{HashMap, HashSet};
+
+use crate::commands::{ModuleStatus, Role, StateGroup, role_name};
+
+/// A project's desired states, labels, modules and members.
+///
+/// Sections left out of the manifest aren't managed at all: they are neither
+/// diffed nor pruned. An empty section (`labels: []`) is managed and, with
+/// `--prune`, deletes everything in it.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// Project ID, identifier or name; `--project` takes precedence.
+    pub project: Option<String>,
+    states: Option<Vec<StateSpec>>,
+    labels: Option<Vec<LabelSpec>>,
+    modules: Option<Vec<ModuleSpec>>,
+    members: Option<Vec<MemberSpec>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StateSpec {
+    name: String,
+    group: String,
+    color: Option<String>,
+    sequence: Option<f64>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LabelSpec {
+    name: String,
+    color: Option<String>,
+    /// Parent label name; the label is top-level when unset.
+    parent: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ModuleSpec {
+    name: String,
+    description: Option<String>,
+    status: Option<String>,
+    /// Workspace member display name, email or ID.
+    lead: Option<String>,
+    start_date: Option<String>,
+    target_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MemberSpec {
+    /// Workspace member display name, email or ID.
+    member: String,
+    #[serde(default = "default_role")]
+    role: String,
+}
+
+fn default_role() -> String {
+    "member".to_string()
+}
+
+impl Manifest {
+    /// Parses a YAML manifest (JSON works too, being valid YAML) and checks
+    /// its values.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut manifest: Self = serde_yaml::from_str(text).context("invalid manifest")?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn validate(&mut self) -> Result<()> {
+        for state in self.states.iter_mut().flatten() {
+            parse_one_of::<StateGroup>("state", &state.name, "group", &state.group)?;
+            normalize_color("state", &state.name, &mut state.color)?;
+        }
+        for label in self.labels.iter_mut().flatten() {
+            normalize_color("label", &label.name, &mut label.color)?;
+            if label
+                .parent
+                .as_ref()
+                .is_some_and(|p| p.eq_ignore_ascii_case(&label.name))
+            {
+                bail!("label '{}' can't be its own parent", label.name);
+            }
+        }
+        for module in self.modules.iter().flatten() {
+            if let Some(status) = &module.status {
+                parse_one_of::<ModuleStatus>("module", &module.name, "status", status)?;
+            }
+        }
+        for member in self.members.iter().flatten() {
+            parse_one_of::<Role>("member", &member.member, "role", &member.role)?;
+        }
+
+        check_unique("state", self.states.iter().flatten().map(|s| &s.name))?;
+        check_unique("label", self.labels.iter().flatten().map(|l| &l.name))?;
+        check_unique("module", self.modules.iter().flatten().map(|m| &m.name))?;
+        check_unique("member", self.members.iter().flatten().map(|m| &m.member))?;
+        Ok(())
+    }
+
+    /// Whether planning needs the workspace member list, to resolve members
+    /// or module leads.
+    pub fn needs_workspace_members(&self) -> bool {
+        self.members.is_some() || self.modules.iter().flatten().any(|m| m.lead.is_some())
+    }
+
+    pub fn manages_states(&self) -> bool {
+        self.states.is_some()
+    }
+
+    pub fn manages_labels(&self) -> bool {
+        self.labels.is_some()
+    }
+
+    pub fn manages_modules(&self) -> bool {
+        self.modules.is_some()
+    }
+
+    pub fn manages_members(&self) -> bool {
+        self.members.is_some()
+    }
+}
+
+/// Parses a value the way the matching command-line flag does, so the
+/// manifest and the CLI accept the same values.
+fn parse_one_of<T: ValueEnum>(kind: &str, name: &str, field: &str, value: &str) -> Result<T> {
+    T::from_str(value, false).map_err(|_| {
+        let allowed: Vec<String> = T::value_variants()
+            .iter()
+            .filter_map(ValueEnum::to_possible_value)
+            .map(|v| v.get_name().to_string())
+            .collect();
+        anyhow!(
+            "{kind} '{name}': unknown {field} '{value}' — expected one of {}",
+            allowed.join(", ")
+        )
+    })
+}
+
+fn normalize_color(kind: &str, name: &str, color: &mut Option<String>) -> Result<()> {
+    if let Some(c) = color {
+        *c = crate::commands::parse_color(c).with_context(|| format!("{kind} '{name}'"))?;
+    }
+    Ok(())
+}
+
+fn check_unique<'a>(kind: &str, names: impl Iterator<Item = &'a String>) -> Result<()> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name.to_lowercase()) {
+            bail!("{kind} '{name}' is listed more than once");
+        }
+    }
+    Ok(())
+}
+
+/// The project as it is now, as returned by the list endpoints.
+#[derive(Default)]
+pub struct Live {
+    pub states: Vec<Value>,
+    pub labels: Vec<Value>,
+    pub modules: Vec<Value>,
+    /// Project members as flattened by [`crate::resolve::project_member`].
+    pub members: Vec<Value>,
+    /// Everyone in the workspace, for resolving members and module leads.
+    pub workspace_members: Vec<Value>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    Create,
+    Update,
+    Delete,
+}
+
+impl Action {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    State,
+    Label,
+    Module,
+    Member,
+}
+
+impl Kind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::State => "state",
+            Self::Label => "label",
+            Self::Module => "module",
+            Self::Member => "member",
+        }
+    }
+
+    /// Path segment of the project endpoint for this kind.
+    pub fn path(self) -> &'static str {
+        match self {
+            Self::State => "states",
+            Self::Label => "labels",
+            Self::Module => "modules",
+            Self::Member => "members",
+        }
+    }
+}
+
+/// One create, update or delete in a plan.
+#[derive(Debug)]
+pub struct Change {
+    pub action: Action,
+    pub kind: Kind,
+    pub name: String,
+    /// The existing object, for updates and deletes.
+    pub id: Option<String>,
+    /// Fields to send.
+    pub body: Map<String, Value>,
+    /// Parent label by name. Resolved while applying, since the parent may be
+    /// created earlier in the same run.
+    pub parent: Option<String>,
+    /// Changed fields as `(field, old, new)`, for display.
+    pub diffs: Vec<(String, String, String)>,
+}
+
+impl Change {
+    fn create(kind: Kind, name: &str, fields: Map<String, Value>) -> Self {
+        let diffs = fields
+            .iter()
+            .map(|(k, v)| (k.clone(), String::new(), display(v)))
+            .collect();
+        let mut body = Map::new();
+        body.insert("name".to_string(), json!(name));
+        body.extend(fields);
+        Self {
+            action: Action::Create,
+            kind,
+            name: name.to_string(),
+            id: None,
+            body,
+            parent: None,
+            diffs,
+        }
+    }
+
+    /// An update sending only the fields of `wanted` that differ from `live`.
+    fn update(kind: Kind, name: &str, live: &Value, wanted: Map<String, Value>) -> Self {
+        let mut change = Self {
+            action: Action::Update,
+            kind,
+            name: name.to_string(),
+            id: live["id"].as_str().map(str::to_string),
+            body: Map::new(),
+            parent: None,
+            diffs: Vec::new(),
+        };
+        for (field, value) in wanted {
+            if !same(&live[&field], &value) {
+                change
+                    .diffs
+                    .push((field.clone(), display(&live[&field]), display(&value)));
+                change.body.insert(field, value);
+            }
+        }
+        change
+    }
+
+    fn delete(kind: Kind, name: &str, live: &Value) -> Self {
+        Self {
+            action: Action::Delete,
+            kind,
+            name: name.to_string(),
+            id: live["id"].as_str().map(str::to_string),
+            body: Map::new(),
+            parent: None,
+            diffs: Vec::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut fields = self.body.clone();
+        if let Some(parent) = &self.parent {
+            fields.insert("parent".to_string(), json!(parent));
+        }
+        json!({
+            "action": self.action.as_str(),
+            "kind": self.kind.as_str(),
+            "name": self.name,
+            "id": self.id,
+            "fields": fields,
+        })
+    }
+}
+
+/// Compares a live value with a wanted one: numbers numerically, strings
+/// case-insensitively for colors' sake, anything else exactly.
+fn same(live: &Value, wanted: &Value) -> bool {
+    match (live, wanted) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (Value::String(a), Value::String(b)) if a.starts_with('#') => a.eq_ignore_ascii_case(b),
+        _ => live == wanted,
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "none".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn name_of(item: &Value) -> &str {
+    item["name"].as_str().unwrap_or("")
+}
+
+fn find_by_name<'a>(items: &'a [Value], name: &str) -> Option<&'a Value> {
+    items.iter().find(|i| name_of(i).eq_ignore_ascii_case(name))
+}
+
+/// Inserts the fields that are set into a map, in order.
+fn fields<const N: usize>(pairs: [(&str, Option<Value>); N]) -> Map<String, Value> {
+    pairs
+        .into_iter()
+        .filter_map(|(k, v)| Some((k.to_string(), v?)))
+        .collect()
+}
+
+/// Works out the changes that bring the live project in line with the
+/// manifest. With `prune`, objects missing from a managed section are deleted.
+pub fn plan(manifest: &Manifest, live: &Live, prune: bool) -> Result<Vec<Change>> {
+    let mut changes = Vec::new();
+    if let Some(states) = &manifest.states {
+        plan_states(states, &live.states, prune, &mut changes);
+    }
+    if let Some(labels) = &manifest.labels {
+        plan_labels(labels, &live.labels, prune, &mut changes)?;
+    }
+    if let Some(modules) = &manifest.modules {
+        plan_modules(modules, live, prune, &mut changes)?;
+    }
+    if let Some(members) = &manifest.members {
+        plan_members(members, live, prune, &mut changes)?;
+    }
+    Ok(changes)
+}
+
+/// Appends deletes for live items whose name isn't wanted.
+fn prune_unlisted<'a>(
+    kind: Kind,
+    live: impl Iterator<Item = &'a Value>,
+    wanted: &HashSet<String>,
+    changes: &mut Vec<Change>,
+) {
+    for item in live {
+        if !wanted.contains(&name_of(item).to_lowercase()) {
+            changes.push(Change::delete(kind, name_of(item), item));
+        }
+    }
+}
+
+fn plan_states(specs: &[StateSpec], live: &[Value], prune: bool, changes: &mut Vec<Change>) {
+    for spec in specs {
+        let wanted = fields([
+            ("group", Some(json!(spec.group))),
+            ("color", spec.color.as_ref().map(|c| json!(c))),
+            ("sequence", spec.sequence.map(|s| json!(s))),
+            ("description", spec.description.as_ref().map(|d| json!(d))),
+        ]);
+        match find_by_name(live, &spec.name) {
+            None => changes.push(Change::create(Kind::State, &spec.name, wanted)),
+            Some(item) => {
+                let change = Change::update(Kind::State, &spec.name, item, wanted);
+                if !change.diffs.is_empty() {
+                    changes.push(change);
+                }
+            }
+        }
+    }
+
+    if prune {
+        let wanted = specs.iter().map(|s| s.name.to_lowercase()).collect();
+        prune_unlisted(Kind::State, live.iter(), &wanted, changes);
+    }
+}
+
+fn plan_labels(
+    specs: &[LabelSpec],
+    live: &[Value],
+    prune: bool,
+    changes: &mut Vec<Change>,
+) -> Result<()> {
+    let live_names: HashMap<&str, &str> = live
+        .iter()
+        .filter_map(|l| Some((l["id"].as_str()?, name_of(l))))
+        .collect();
+    let specs_by_name: HashMap<String, &LabelSpec> =
+        specs.iter().map(|s| (s.name.to_lowercase(), s)).collect();
+
+    // Parents are created before their children: order by depth in the manifest.
+    let mut ordered: Vec<(usize, &LabelSpec)> = Vec::with_capacity(specs.len());
+    for spec in specs {
+        let mut depth = 0;
+        let mut parent = spec.parent.as_deref();
+        while let Some(p) = parent {
+            if !specs_by_name.contains_key(&p.to_lowercase()) && find_by_name(live, p).is_none() {
+                bail!("label '{}': unknown parent '{p}'", spec.name);
+            }
+            depth += 1;
+            if depth > specs.len() {
+                bail!("label '{}': parents form a cycle", spec.name);
+            }
+            parent = specs_by_name
+                .get(&p.to_lowercase())
+                .and_then(|s| s.parent.as_deref());
+        }
+        ordered.push((depth, spec));
+    }
+    ordered.sort_by_key(|(depth, _)| *depth);
+
+    for (_, spec) in ordered {
+        let wanted = fields([
+            ("color", spec.color.as_ref().map(|c| json!(c))),
+            ("description", spec.description.as_ref().map(|d| json!(d))),
+        ]);
+        match find_by_name(live, &spec.name) {
+            None => {
+                let mut change = Change::create(Kind::Label, &spec.name, wanted);
+                if let Some(parent) = &spec.parent {
+                    change
+                        .diffs
+                        .push(("parent".to_string(), String::new(), parent.clone()));
+                    change.parent = Some(parent.clone());
+                }
+                changes.push(change);
+            }
+            Some(item) => {
+                let mut change = Change::update(Kind::Label, &spec.name, item, wanted);
+                let current = item["parent"]
+                    .as_str()
+                    .and_then(|id| live_names.get(id).copied());
+                let moved = match (current, spec.parent.as_deref()) {
+                    (Some(a), Some(b)) => !a.eq_ignore_ascii_case(b),
+                    (None, None) => false,
+                    _ => true,
+                };
+                if moved {
+                    change.diffs.push((
+                        "parent".to_string(),
+                        current.unwrap_or("none").to_string(),
+                        spec.parent.clone().unwrap_or_else(|| "none".to_string()),
+                    ));
+                    match &spec.parent {
+                        Some(parent) => change.parent = Some(parent.clone()),
+                        None => {
+                            change.body.insert("parent".to_string(), Value::Null);
+                        }
+                    }
+                }
+                if !change.diffs.is_empty() {
+                    changes.push(change);
+                }
+            }
+        }
+    }
+
+    if prune {
+        let wanted = specs.iter().map(|s| s.name.to_lowercase()).collect();
+        // Children go before their parents.
+        let mut unlisted: Vec<&Value> = live.iter().collect();
+        unlisted.sort_by_key(|l| std::cmp::Reverse(live_depth(l, live)));
+        prune_unlisted(Kind::Label, unlisted.into_iter(), &wanted, changes);
+    }
+    Ok(())
+}
+
+/// How many ancestors a live label has.
+fn live_depth(label: &Value, labels: &[Value]) -> usize {
+    let mut depth = 0;
+    let mut parent = label["parent"].as_str();
+    while let Some(id) = parent {
+        depth += 1;
+        if depth > labels.len() {
+            break;
+        }
+        parent = labels
+            .iter()
+            .find(|l| l["id"].as_str() == Some(id))
+            .and_then(|l| l["parent"].as_str());
+    }
+    depth
+}
+
+/// Finds a workspace member by ID, email or display name.
+fn person<'a>(people: &'a [Value], value: &str) -> Result<&'a Value> {
+    let matches: Vec<&Value> = people
+        .iter()
+        .filter(|p| {
+            p["id"].as_str() == Some(value)
+                || ["email", "display_name"].iter().any(|k| {
+                    p[*k]
+                        .as_str()
+                        .is_some_and(|s| s.eq_ignore_ascii_case(value))
+                })
+        })
+        .collect();
+    match matches.as_slice() {
+        [one] => Ok(one),
+        [] => bail!("unknown workspace member '{value}'"),
+        _ => bail!("workspace member '{value}' is ambiguous — use an email or ID"),
+    }
+}
+
+fn plan_modules(
+    specs: &[ModuleSpec],
+    live: &Live,
+    prune: bool,
+    changes: &mut Vec<Change>,
+) -> Result<()> {
+    for spec in specs {
+        let lead = match &spec.lead {
+            Some(l) => Some(
+                person(&live.workspace_members, l)
+                    .with_context(|| format!("module '{}'", spec.name))?["id"]
+                    .clone(),
+            ),
+            None => None,
+        };
+        let wanted = fields([
+            ("description", spec.description.as_ref().map(|d| json!(d))),
+            ("status", spec.status.as_ref().map(|s| json!(s))),
+            ("lead", lead),
+            ("start_date", spec.start_date.as_ref().map(|d| json!(d))),
+            ("target_date", spec.target_date.as_ref().map(|d| json!(d))),
+        ]);
+        match find_by_name(&live.modules, &spec.name) {
+            None => changes.push(Change::create(Kind::Module, &spec.name, wanted)),
+            Some(item) => {
+                let change = Change::update(Kind::Module, &spec.name, item, wanted);
+                if !change.diffs.is_empty() {
+                    changes.push(change);
+                }
+            }
+        }
+    }
+
+    if prune {
+        let wanted = specs.iter().map(|s| s.name.to_lowercase()).collect();
+        prune_unlisted(Kind::Module, live.modules.iter(), &wanted, changes);
+    }
+    Ok(())
+}
+
+fn plan_members(
+    specs: &[MemberSpec],
+    live: &Live,
+    prune: bool,
+    changes: &mut Vec<Change>,
+) -> Result<()> {
+    let mut listed = HashSet::new();
+    for spec in specs {
+        let user = person(&live.workspace_members, &spec.member)?;
+        let id = user["id"].as_str().unwrap_or_default();
+        let name = user["display_name"].as_str().unwrap_or(&spec.member);
+        let role = Role::from_str(&spec.role, false).map_or(15, |r| r.code());
+        listed.insert(id.to_string());
+
+        match live.members.iter().find(|m| m["id"].as_str() == Some(id)) {
+            None => {
+                let mut change = Change::create(Kind::Member, name, Map::new());
+                change.body = fields([("member", Some(json!(id))), ("role", Some(json!(role)))]);
+                change.diffs = vec![("role".to_string(), String::new(), spec.role.clone())];
+                changes.push(change);
+            }
+            // Only compare roles the server reports.
+            Some(member) if member["role"].is_u64() && member["role"].as_u64() != Some(role) => {
+                let mut change = Change::update(
+                    Kind::Member,
+                    name,
+                    member,
+                    fields([("role", Some(json!(role)))]),
+                );
+                change.id = membership_id(member);
+                change.diffs = vec![(
+                    "role".to_string(),
+                    role_name(&member["role"]),
+                    spec.role.clone(),
+                )];
+                changes.push(change);
+            }
+            Some(_) => {}
+        }
+    }
+
+    if prune {
+        for member in &live.members {
+            let id = member["id"].as_str().unwrap_or_default();
+            if !listed.contains(id) {
+                let name = member["display_name"]
+                    .as_str()
+                    .or_else(|| member["email"].as_str())
+                    .unwrap_or(id);
+                let mut change = Change::delete(Kind::Member, name, member);
+                change.id = membership_id(member);
+                changes.push(change);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The ID that `members/{id}/` expects: the membership when the server
+/// reported one, the user otherwise.
+fn membership_id(member: &Value) -> Option<String> {
+    member["membership"]
+        .as_str()
+        .or_else(|| member["id"].as_str())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn live() -> Live {
+        Live {
+            states: vec![
+                json!({"id": "s1", "name": "Todo", "group": "unstarted", "color": "#AAAAAA"}),
+                json!({"id": "s2", "name": "Done", "group": "completed", "color": "#000000"}),
+                json!({"id": "s3", "name": "Legacy", "group": "started", "color": "#111111"}),
+            ],
+            labels: vec![
+                json!({"id": "l1", "name": "Severity", "parent": null}),
+                json!({"id": "l2", "name": "high", "parent": "l1"}),
+                json!({"id": "l3", "name": "old", "parent": null}),
+            ],
+            modules: vec![json!({"id": "m1", "name": "Billing", "status": "planned"})],
+            members: vec![
+                json!({"id": "u1", "membership": "pm1", "display_name": "alice", "role": 20}),
+                json!({"id": "u2", "membership": "pm2", "display_name": "bob", "role": 15}),
+            ],
+            workspace_members: vec![
+                json!({"id": "u1", "display_name": "alice", "email": "alice@example.com"}),
+                json!({"id": "u2", "display_name": "bob", "email": "bob@example.com"}),
+                json!({"id": "u3", "display_name": "carol", "email": "carol@example.com"}),
+            ],
+        }
+    }
+
+    fn summary(changes: &[Change]) -> Vec<String> {
+        changes
+            .iter()
+            .map(|c| format!("{} {} {}", c.action.as_str(), c.kind.as_str(), c.name))
+            .collect()
+    }
+
+    #[test]
+    fn parse_rejects_unknown_values() {
+        let err = Manifest::parse("states:\n  - name: QA\n    group: testing\n").unwrap_err();
+        assert!(err.to_string().contains("unknown group 'testing'"), "{err}");
+
+        let err = Manifest::parse("labels:\n  - name: bug\n    color: red\n").unwrap_err();
+        assert!(
+            format!("{err:#}").contains("invalid color 'red'"),
+            "{err:#}"
+        );
+
+        let err = Manifest::parse("members:\n  - member: a\n    role: owner\n").unwrap_err();
+        assert!(err.to_string().contains("unknown role 'owner'"), "{err}");
+
+        assert!(Manifest::parse("cycles: []\n").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_duplicates() {
+        let err = Manifest::parse("labels:\n  - name: bug\n  - name: Bug\n").unwrap_err();
+        assert!(err.to_string().contains("listed more than once"), "{err}");
+    }
+
+    #[test]
+    fn parse_accepts_json() {
+        let manifest =
+            Manifest::parse(r#"{"project": "WEB", "labels": [{"name": "bug"}]}"#).unwrap();
+        assert_eq!(manifest.project.as_deref(), Some("WEB"));
+        assert!(manifest.manages_labels());
+        assert!(!manifest.manages_states());
+    }
+
+    #[test]
+    fn plan_states_creates_and_updates_only_differences() {
+        let manifest = Manifest::parse(
+            "states:\n\
+             - {name: todo, group: unstarted, color: '#aaaaaa'}\n\
+             - {name: Done, group: completed, color: '#22c55e'}\n\
+             - {name: Review, group: started, color: '#3a86ff'}\n",
+        )
+        .unwrap();
+        let changes = plan(&manifest, &live(), false).unwrap();
+        assert_eq!(
+            summary(&changes),
+            ["update state Done", "create state Review"]
+        );
+        assert_eq!(changes[0].body, fields([("color", Some(json!("#22c55e")))]));
+        assert_eq!(changes[0].id.as_deref(), Some("s2"));
+        assert_eq!(changes[1].body["name"], "Review");
+    }
+
+    #[test]
+    fn prune_only_deletes_in_managed_sections() {
+        let manifest = Manifest::parse(
+            "states:\n\
+             - {name: Todo, group: unstarted}\n\
+             - {name: Done, group: completed}\n",
+        )
+        .unwrap();
+        let changes = plan(&manifest, &live(), true).unwrap();
+        assert_eq!(summary(&changes), ["delete state Legacy"]);
+
+        let changes = plan(&manifest, &live(), false).unwrap();
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn plan_labels_orders_parents_first_and_moves_labels() {
+        let manifest = Manifest::parse(
+            "labels:\n\
+             - {name: critical, parent: Area}\n\
+             - {name: Area}\n\
+             - {name: Severity}\n\
+             - {name: high}\n",
+        )
+        .unwrap();
+        let changes = plan(&manifest, &live(), true).unwrap();
+        assert_eq!(
+            summary(&changes),
+            [
+                "create label Area",
+                "update label high",
+                "create label critical",
+                "delete label old"
+            ]
+        );
+        assert_eq!(changes[1].body["parent"], Value::Null);
+        assert_eq!(changes[2].parent.as_deref(), Some("Area"));
+    }
+
+    #[test]
+    fn plan_labels_rejects_unknown_parent() {
+        let manifest = Manifest::parse("labels:\n  - {name: a, parent: nowhere}\n").unwrap();
+        let err = plan(&manifest, &live(), false).unwrap_err();
+        assert!(
+            err.to_string().contains("unknown parent 'nowhere'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn plan_labels_prunes_children_before_parents() {
+        let manifest = Manifest::parse("labels: []\n").unwrap();
+        let changes = plan(&manifest, &live(), true).unwrap();
+        let names: Vec<&str> = changes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names[0], "high");
+        assert_eq!(changes.len(), 3);
+    }
+
+    #[test]
+    fn plan_modules_resolves_lead() {
+        let manifest = Manifest::parse(
+            "modules:\n\
+             - {name: Billing, status: in-progress, lead: carol@example.com}\n",
+        )
+        .unwrap();
+        let changes = plan(&manifest, &live(), false).unwrap();
+        assert_eq!(summary(&changes), ["update module Billing"]);
+        assert_eq!(changes[0].body["lead"], "u3");
+        assert_eq!(changes[0].body["status"], "in-progress");
+    }
+
+    #[test]
+    fn plan_members_adds_changes_roles_and_prunes() {
+        let manifest = Manifest::parse(
+            "members:\n\
+             - {member: alice, role: guest}\n\
+             - {member: carol@example.com}\n",
+        )
+        .unwrap();
+        let changes = plan(&manifest, &live(), true).unwrap();
+        assert_eq!(
+            summary(&changes),
+            [
+                "update member alice",
+                "create member carol",
+                "delete member bob"
+            ]
+        );
+        assert_eq!(changes[0].id.as_deref(), Some("pm1"));
+        assert_eq!(changes[0].body, fields([("role", Some(json!(5)))]));
+        assert_eq!(
+            changes[1].body,
+            fields([("member", Some(json!("u3"))), ("role", Some(json!(15)))])
+        );
+        assert_eq!(changes[2].id.as_deref(), Some("pm2"));
+    }
+
+    #[test]
+    fn plan_members_rejects_unknown_person() {
+        let manifest = Manifest::parse("members:\n  - member: dave\n").unwrap();
+        let err = plan(&manifest, &live(), false).unwrap_err();
+        assert!(err.to_string().contains("unknown workspace member 'dave'"));
+    }
+}
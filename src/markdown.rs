@@ -0,0 +1,93 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, html};
+
+/// Converts CommonMark (with GFM tables, task lists and strikethrough) into the
+/// HTML Plane's editor stores in `description_html` and `comment_html`.
+///
+/// Task lists are emitted in the editor's own markup
+/// (`<ul data-type="taskList">` / `<li data-type="taskItem">`) so checkboxes
+/// stay interactive in the web UI.
+pub fn to_html(markdown: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH;
+    let events: Vec<Event> = Parser::new_ext(markdown, options).collect();
+
+    let mut out_events = Vec::with_capacity(events.len());
+    let mut i = 0;
+    while i < events.len() {
+        match (&events[i], events.get(i + 1), events.get(i + 2)) {
+            (
+                Event::Start(Tag::List(None)),
+                Some(Event::Start(Tag::Item)),
+                Some(Event::TaskListMarker(_)),
+            ) => {
+                out_events.push(Event::Html("<ul data-type=\"taskList\">\n".into()));
+            }
+            (Event::Start(Tag::Item), Some(Event::TaskListMarker(checked)), _) => {
+                out_events.push(Event::Html(
+                    format!("<li data-type=\"taskItem\" data-checked=\"{checked}\">").into(),
+                ));
+                i += 1; // skip the marker itself
+            }
+            (Event::End(TagEnd::List(false)), _, _) => {
+                out_events.push(Event::Html("</ul>\n".into()));
+            }
+            (event, _, _) => out_events.push(event.clone()),
+        }
+        i += 1;
+    }
+
+    let mut out = String::new();
+    html::push_html(&mut out, out_events.into_iter());
+    out.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paragraphs_and_inline() {
+        assert_eq!(
+            to_html("Hello **world** & `code`"),
+            "<p>Hello <strong>world</strong> &amp; <code>code</code></p>"
+        );
+    }
+
+    #[test]
+    fn test_task_list() {
+        let html = to_html("- [x] done\n- [ ] todo");
+        assert_eq!(
+            html,
+            "<ul data-type=\"taskList\">\n\
+             <li data-type=\"taskItem\" data-checked=\"true\">done</li>\n\
+             <li data-type=\"taskItem\" data-checked=\"false\">todo</li>\n\
+             </ul>"
+        );
+    }
+
+    #[test]
+    fn test_plain_list_is_untouched() {
+        assert_eq!(
+            to_html("- one\n- two"),
+            "<ul>\n<li>one</li>\n<li>two</li>\n</ul>"
+        );
+    }
+
+    #[test]
+    fn test_code_block() {
+        assert_eq!(
+            to_html("```rust\nlet x = 1;\n```"),
+            "<pre><code class=\"language-rust\">let x = 1;\n</code></pre>"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let html = to_html("| a | b |\n|---|---|\n| 1 | 2 |");
+        assert!(html.starts_with("<table>"), "got: {html}");
+        assert!(
+            html.contains("<th>a</th>") && html.contains("<td>2</td>"),
+            "got: {html}"
+        );
+    }
+}
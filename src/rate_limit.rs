@@ -0,0 +1,142 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token-bucket limiter shared by all requests made through one `Client`.
+///
+/// Each request reserves a token up front; when the bucket is empty the caller
+/// is told how long to wait for its turn, so concurrent requests queue up
+/// instead of racing into a 429. The bucket is also tightened from the
+/// server's `X-RateLimit-Remaining` header when it reports fewer requests left
+/// than we expect.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Tokens added per second.
+    rate: f64,
+    capacity: f64,
+    state: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    /// Set when the server reports an exhausted quota; no request may start
+    /// before this instant.
+    blocked_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `requests` per minute, or `None` when
+    /// `requests` is zero (limiting disabled).
+    pub fn per_minute(requests: u32) -> Option<Self> {
+        if requests == 0 {
+            return None;
+        }
+        let capacity = f64::from(requests);
+        Some(Self {
+            rate: capacity / 60.0,
+            capacity,
+            state: Mutex::new(Bucket {
+                tokens: capacity,
+                updated: Instant::now(),
+                blocked_until: None,
+            }),
+        })
+    }
+
+    /// Reserves a token and returns how long the caller must wait before
+    /// sending its request.
+    pub fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self.state.lock().unwrap();
+        self.refill(&mut bucket, now);
+
+        bucket.tokens -= 1.0;
+        let bucket_wait = if bucket.tokens < 0.0 {
+            Duration::from_secs_f64(-bucket.tokens / self.rate)
+        } else {
+            Duration::ZERO
+        };
+        let server_wait = bucket
+            .blocked_until
+            .map(|t| t.saturating_duration_since(now))
+            .unwrap_or_default();
+
+        bucket_wait.max(server_wait)
+    }
+
+    /// Feeds the server's view of the quota back into the bucket.
+    /// `reset` is the time until the server's window resets.
+    pub fn observe(&self, remaining: Option<u64>, reset: Option<Duration>, now: Instant) {
+        let Some(remaining) = remaining else {
+            return;
+        };
+        let mut bucket = self.state.lock().unwrap();
+        self.refill(&mut bucket, now);
+
+        bucket.tokens = bucket.tokens.min(remaining as f64);
+        if remaining == 0
+            && let Some(reset) = reset
+        {
+            bucket.blocked_until = Some(now + reset);
+        }
+    }
+
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.capacity);
+        bucket.updated = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_when_zero() {
+        assert!(RateLimiter::per_minute(0).is_none());
+    }
+
+    #[test]
+    fn test_burst_up_to_capacity_then_waits() {
+        let limiter = RateLimiter::per_minute(60).unwrap();
+        let now = Instant::now();
+        for _ in 0..60 {
+            assert_eq!(limiter.reserve(now), Duration::ZERO);
+        }
+        // One token per second at 60/min; concurrent callers queue up.
+        assert_eq!(limiter.reserve(now), Duration::from_secs(1));
+        assert_eq!(limiter.reserve(now), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_refills_over_time() {
+        let limiter = RateLimiter::per_minute(60).unwrap();
+        let now = Instant::now();
+        for _ in 0..60 {
+            limiter.reserve(now);
+        }
+        let later = now + Duration::from_secs(5);
+        for _ in 0..5 {
+            assert_eq!(limiter.reserve(later), Duration::ZERO);
+        }
+        assert!(limiter.reserve(later) > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_observe_remaining_drains_bucket() {
+        let limiter = RateLimiter::per_minute(60).unwrap();
+        let now = Instant::now();
+        limiter.observe(Some(1), None, now);
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(limiter.reserve(now), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_observe_exhausted_quota_blocks_until_reset() {
+        let limiter = RateLimiter::per_minute(60).unwrap();
+        let now = Instant::now();
+        limiter.observe(Some(0), Some(Duration::from_secs(30)), now);
+        assert_eq!(limiter.reserve(now), Duration::from_secs(30));
+    }
+}
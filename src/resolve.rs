@@ -0,0 +1,633 @@
+use anyhow::{Context, Result, bail};
+
+use crate::client::Client;
+
+/// An issue reference resolved to the IDs the project-scoped endpoints expect.
+#[derive(Debug)]
+pub struct ResolvedIssue {
+    pub project: String,
+    pub id: String,
+}
+
+/// Resolves an issue reference given either as an identifier (`WEB-123`) or as an ID.
+///
+/// Identifiers are looked up through the workspace-level issue endpoint, so no
+/// project is needed. Plain IDs require the project to be passed explicitly;
+/// it may itself be a name or identifier (see [`project`]).
+pub async fn issue(
+    client: &Client,
+    workspace: &str,
+    project: Option<&str>,
+    reference: &str,
+) -> Result<ResolvedIssue> {
+    let identifier = reference.to_uppercase();
+    if let Some(project) = project
+        && !is_identifier(&identifier)
+    {
+        return Ok(ResolvedIssue {
+            project: self::project(client, workspace, project).await?,
+            id: reference.to_string(),
+        });
+    }
+
+    if !is_identifier(&identifier) {
+        bail!(
+            "project is required for issue '{reference}' — pass --project or use an identifier like WEB-123"
+        );
+    }
+
+    let data = client
+        .get(&format!("workspaces/{workspace}/issues/{identifier}/"))
+        .await
+        .with_context(|| format!("failed to look up issue {identifier}"))?;
+
+    let id = data["id"]
+        .as_str()
+        .context("unexpected response format: missing issue 'id'")?;
+    let project_id = data["project"]
+        .as_str()
+        .or_else(|| data["project_id"].as_str())
+        .context("unexpected response format: missing issue 'project'")?;
+
+    if let Some(project) = project
+        && self::project(client, workspace, project).await? != project_id
+    {
+        bail!("issue {identifier} is not in project '{project}'");
+    }
+
+    Ok(ResolvedIssue {
+        project: project_id.to_string(),
+        id: id.to_string(),
+    })
+}
+
+/// Resolves a project given as an ID, identifier (`WEB`) or name.
+pub async fn project(client: &Client, workspace: &str, value: &str) -> Result<String> {
+    if is_uuid(value) {
+        return Ok(value.to_string());
+    }
+    let items = client
+        .fetch_all(&format!("workspaces/{workspace}/projects/"))
+        .await?;
+    pick("project", value, &items, &["identifier", "name"])
+}
+
+/// Resolves a state given as an ID or name.
+pub async fn state(client: &Client, workspace: &str, project: &str, value: &str) -> Result<String> {
+    let path = format!("workspaces/{workspace}/projects/{project}/states/");
+    let mut ids = lookup(client, &path, "state", &["name"], &[value]).await?;
+    Ok(ids.remove(0))
+}
+
+/// Resolves a label given as an ID or name.
+pub async fn label(client: &Client, workspace: &str, project: &str, value: &str) -> Result<String> {
+    let mut ids = labels(client, workspace, project, &[value.to_string()]).await?;
+    Ok(ids.remove(0))
+}
+
+/// Resolves labels given as IDs or names.
+pub async fn labels(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    values: &[String],
+) -> Result<Vec<String>> {
+    let path = format!("workspaces/{workspace}/projects/{project}/labels/");
+    lookup(client, &path, "label", &["name"], values).await
+}
+
+/// Resolves a cycle given as an ID or name.
+pub async fn cycle(client: &Client, workspace: &str, project: &str, value: &str) -> Result<String> {
+    let path = format!("workspaces/{workspace}/projects/{project}/cycles/");
+    let mut ids = lookup(client, &path, "cycle", &["name"], &[value]).await?;
+    Ok(ids.remove(0))
+}
+
+/// Resolves a module given as an ID or name.
+pub async fn module(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    value: &str,
+) -> Result<String> {
+    let path = format!("workspaces/{workspace}/projects/{project}/modules/");
+    let mut ids = lookup(client, &path, "module", &["name"], &[value]).await?;
+    Ok(ids.remove(0))
+}
+
+/// Resolves a project member given as an ID, display name or email.
+pub async fn member(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    value: &str,
+) -> Result<String> {
+    let mut ids = members(client, workspace, project, &[value]).await?;
+    Ok(ids.remove(0))
+}
+
+/// Resolves project members given as IDs, display names or emails to their
+/// user IDs.
+pub async fn members<S: AsRef<str>>(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    values: &[S],
+) -> Result<Vec<String>> {
+    if values.iter().all(|v| is_uuid(v.as_ref())) {
+        return Ok(values.iter().map(|v| v.as_ref().to_string()).collect());
+    }
+    let path = format!("workspaces/{workspace}/projects/{project}/members/");
+    let items: Vec<serde_json::Value> = client
+        .fetch_all(&path)
+        .await?
+        .iter()
+        .map(project_member)
+        .collect();
+    pick_all("member", &items, &["display_name", "email"], values)
+}
+
+/// Resolves a project member given as a user ID, display name or email to the
+/// ID of their project membership, which `members/{id}/` expects.
+///
+/// When the server lists bare users rather than memberships, there is no
+/// separate membership ID and the user ID is returned.
+pub async fn membership(
+    client: &Client,
+    workspace: &str,
+    project: &str,
+    value: &str,
+) -> Result<String> {
+    let path = format!("workspaces/{workspace}/projects/{project}/members/");
+    let items: Vec<serde_json::Value> = client
+        .fetch_all(&path)
+        .await?
+        .iter()
+        .map(|entry| {
+            let member = project_member(entry);
+            serde_json::json!({
+                "id": member["membership"],
+                "user": member["id"],
+                "display_name": member["display_name"],
+                "email": member["email"],
+            })
+        })
+        .collect();
+    pick("member", value, &items, &["user", "display_name", "email"])
+}
+
+/// Flattens an entry of a project's member list into
+/// `{id, membership, display_name, email, role}`, where `id` is the user ID.
+///
+/// Entries are either bare users or memberships whose `member` is the user
+/// object or the user ID; for bare users `membership` is the user ID.
+pub fn project_member(entry: &serde_json::Value) -> serde_json::Value {
+    let (user_id, user) = match &entry["member"] {
+        serde_json::Value::Object(_) => (&entry["member"]["id"], &entry["member"]),
+        serde_json::Value::String(_) => (&entry["member"], entry),
+        _ => (&entry["id"], entry),
+    };
+    serde_json::json!({
+        "id": user_id,
+        "membership": entry["id"],
+        "display_name": user["display_name"],
+        "email": user["email"],
+        "role": entry["role"],
+    })
+}
+
+/// Resolves a workspace member given as an ID, display name or email.
+pub async fn workspace_member(client: &Client, workspace: &str, value: &str) -> Result<String> {
+    let path = format!("workspaces/{workspace}/members/");
+    let mut ids = lookup(
+        client,
+        &path,
+        "member",
+        &["display_name", "email"],
+        &[value],
+    )
+    .await?;
+    Ok(ids.remove(0))
+}
+
+/// Resolves every value against the list at `path`, fetching it at most once.
+/// UUIDs are passed through without a request.
+async fn lookup<S: AsRef<str>>(
+    client: &Client,
+    path: &str,
+    kind: &str,
+    keys: &[&str],
+    values: &[S],
+) -> Result<Vec<String>> {
+    if values.iter().all(|v| is_uuid(v.as_ref())) {
+        return Ok(values.iter().map(|v| v.as_ref().to_string()).collect());
+    }
+
+    let items = client.fetch_all(path).await?;
+    pick_all(kind, &items, keys, values)
+}
+
+/// Picks an item for every value, passing UUIDs through unchanged.
+fn pick_all<S: AsRef<str>>(
+    kind: &str,
+    items: &[serde_json::Value],
+    keys: &[&str],
+    values: &[S],
+) -> Result<Vec<String>> {
+    values
+        .iter()
+        .map(|v| {
+            let v = v.as_ref();
+            if is_uuid(v) {
+                Ok(v.to_string())
+            } else {
+                pick(kind, v, items, keys)
+            }
+        })
+        .collect()
+}
+
+/// Picks the single item whose ID equals `value` or whose `keys` match it
+/// case-insensitively. Errors list close matches when nothing or more than
+/// one item matches.
+fn pick(kind: &str, value: &str, items: &[serde_json::Value], keys: &[&str]) -> Result<String> {
+    if let Some(item) = items.iter().find(|i| i["id"].as_str() == Some(value)) {
+        return Ok(item["id"].as_str().unwrap_or_default().to_string());
+    }
+
+    let needle = value.to_lowercase();
+    let matches: Vec<&serde_json::Value> = items
+        .iter()
+        .filter(|item| {
+            keys.iter()
+                .filter_map(|k| item[*k].as_str())
+                .any(|s| s.to_lowercase() == needle)
+        })
+        .collect();
+
+    match matches.as_slice() {
+        [item] => item["id"]
+            .as_str()
+            .map(str::to_string)
+            .with_context(|| format!("{kind} '{value}' has no ID")),
+        [] => {
+            let suggestions: Vec<String> = items
+                .iter()
+                .filter_map(|item| {
+                    keys.iter()
+                        .filter_map(|k| item[*k].as_str())
+                        .find(|s| is_similar(&needle, &s.to_lowercase()))
+                })
+                .map(str::to_string)
+                .take(5)
+                .collect();
+            if suggestions.is_empty() {
+                bail!("unknown {kind} '{value}'");
+            }
+            bail!(
+                "unknown {kind} '{value}' — did you mean: {}?",
+                suggestions.join(", ")
+            )
+        }
+        many => {
+            let candidates: Vec<String> = many
+                .iter()
+                .map(|item| {
+                    let label = keys
+                        .iter()
+                        .filter_map(|k| item[*k].as_str())
+                        .collect::<Vec<_>>()
+                        .join(" / ");
+                    format!("{label} ({})", item["id"].as_str().unwrap_or(""))
+                })
+                .collect();
+            bail!(
+                "ambiguous {kind} '{value}' — did you mean one of: {}? Pass the ID instead",
+                candidates.join(", ")
+            )
+        }
+    }
+}
+
+/// Loose match used for "did you mean" suggestions.
+fn is_similar(needle: &str, candidate: &str) -> bool {
+    candidate.contains(needle)
+        || needle.contains(candidate)
+        || edit_distance(needle, candidate) <= 2
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+/// Returns true for canonical hyphenated UUIDs.
+pub fn is_uuid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    let lengths = [8, 4, 4, 4, 12];
+    groups.len() == lengths.len()
+        && groups
+            .iter()
+            .zip(lengths)
+            .all(|(g, len)| g.len() == len && g.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Returns true for issue identifiers like `WEB-123`: an uppercase project
+/// identifier followed by a dash and a sequence number.
+pub fn is_identifier(value: &str) -> bool {
+    let Some((prefix, seq)) = value.rsplit_once('-') else {
+        return false;
+    };
+    prefix.starts_with(|c: char| c.is_ascii_uppercase())
+        && prefix
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        && !seq.is_empty()
+        && seq.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::Settings;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn test_client(base_url: &str) -> Client {
+        let settings = Settings {
+            api_key: Some("test-key".to_string()),
+            base_url: base_url.to_string(),
+            workspace: Some("test-ws".to_string()),
+            timeout: 5,
+            ..Settings::default()
+        };
+        Client::new(&settings, true).unwrap()
+    }
+
+    // ── Reference parsing ──
+
+    #[test]
+    fn test_is_uuid() {
+        assert!(is_uuid("2f1c7a52-6a43-4b65-9c1e-3d2a9f0b8e71"));
+        assert!(!is_uuid("WEB-123"));
+        assert!(!is_uuid("2f1c7a52-6a43-4b65-9c1e"));
+    }
+
+    #[test]
+    fn test_is_identifier() {
+        assert!(is_identifier("WEB-123"));
+        assert!(is_identifier("API2-7"));
+        assert!(!is_identifier("web-123"));
+        assert!(!is_identifier("WEB-"));
+        assert!(!is_identifier("-123"));
+        assert!(!is_identifier("2f1c7a52-6a43-4b65-9c1e-301234567890"));
+    }
+
+    // ── pick ──
+
+    fn states() -> Vec<serde_json::Value> {
+        vec![
+            serde_json::json!({"id": "s1", "name": "Todo"}),
+            serde_json::json!({"id": "s2", "name": "In Progress"}),
+            serde_json::json!({"id": "s3", "name": "In Review"}),
+        ]
+    }
+
+    #[test]
+    fn test_pick_matches_name_case_insensitively() {
+        assert_eq!(pick("state", "todo", &states(), &["name"]).unwrap(), "s1");
+    }
+
+    #[test]
+    fn test_pick_matches_id() {
+        assert_eq!(pick("state", "s2", &states(), &["name"]).unwrap(), "s2");
+    }
+
+    #[test]
+    fn test_pick_unknown_suggests_close_matches() {
+        let err = pick("state", "In Progres", &states(), &["name"]).unwrap_err();
+        let msg = format!("{err:#}");
+        assert!(msg.contains("unknown state"), "got: {msg}");
+        assert!(msg.contains("did you mean: In Progress"), "got: {msg}");
+    }
+
+    #[test]
+    fn test_pick_ambiguous_lists_candidates() {
+        let items = vec![
+            serde_json::json!({"id": "m1", "display_name": "alex", "email": "a@x.io"}),
+            serde_json::json!({"id": "m2", "display_name": "Alex", "email": "b@x.io"}),
+        ];
+        let err = pick("member", "alex", &items, &["display_name", "email"]).unwrap_err();
+        let msg = format!("{err:#}");
+        assert!(msg.contains("ambiguous member"), "got: {msg}");
+        assert!(msg.contains("m1") && msg.contains("m2"), "got: {msg}");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
+    // ── Resolution ──
+
+    #[tokio::test]
+    async fn test_project_by_identifier() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/projects/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [
+                    {"id": "p1", "name": "Website", "identifier": "WEB"},
+                    {"id": "p2", "name": "Backend", "identifier": "API"}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        assert_eq!(project(&client, "test-ws", "api").await.unwrap(), "p2");
+    }
+
+    #[tokio::test]
+    async fn test_project_follows_pagination() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/projects/"))
+            .and(query_param("cursor", "100:1:0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [{"id": "p2", "name": "Backend", "identifier": "API"}],
+                "next_page_results": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/projects/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [{"id": "p1", "name": "Website", "identifier": "WEB"}],
+                "next_cursor": "100:1:0",
+                "next_page_results": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        assert_eq!(project(&client, "test-ws", "API").await.unwrap(), "p2");
+    }
+
+    #[tokio::test]
+    async fn test_members_fetches_list_once() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/projects/p1/members/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": "m1", "display_name": "alice", "email": "alice@example.com"},
+                {"id": "m2", "display_name": "bob", "email": "bob@example.com"}
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let ids = members(&client, "test-ws", "p1", &["alice", "bob@example.com"])
+            .await
+            .unwrap();
+        assert_eq!(ids, vec!["m1", "m2"]);
+    }
+
+    #[tokio::test]
+    async fn test_members_reads_membership_entries() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/projects/p1/members/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": "pm1", "role": 15, "member": {"id": "u1", "display_name": "alice"}}
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let ids = members(&client, "test-ws", "p1", &["alice"]).await.unwrap();
+        assert_eq!(ids, vec!["u1"]);
+        let id = membership(&client, "test-ws", "p1", "alice").await.unwrap();
+        assert_eq!(id, "pm1");
+        let id = membership(&client, "test-ws", "p1", "u1").await.unwrap();
+        assert_eq!(id, "pm1");
+    }
+
+    #[test]
+    fn test_project_member_shapes() {
+        let user = serde_json::json!({"id": "u1", "display_name": "alice", "role": 20});
+        let flat = project_member(&user);
+        assert_eq!(flat["id"], "u1");
+        assert_eq!(flat["membership"], "u1");
+        assert_eq!(flat["role"], 20);
+
+        let by_id = serde_json::json!({"id": "pm1", "member": "u1", "role": 5});
+        let flat = project_member(&by_id);
+        assert_eq!(flat["id"], "u1");
+        assert_eq!(flat["membership"], "pm1");
+    }
+
+    #[tokio::test]
+    async fn test_issue_with_project_and_id_skips_lookup() {
+        let client = test_client("http://127.0.0.1:9");
+        let project = "0b6f2c1e-5d3a-4e7b-9f8c-1a2b3c4d5e6f";
+        let id = "9f8e7d6c-5b4a-4392-8170-6f5e4d3c2b1a";
+        let resolved = issue(&client, "test-ws", Some(project), id).await.unwrap();
+        assert_eq!(resolved.project, project);
+        assert_eq!(resolved.id, id);
+    }
+
+    #[tokio::test]
+    async fn test_issue_lowercase_identifier_with_project_is_looked_up() {
+        let mock_server = MockServer::start().await;
+        let project = "0b6f2c1e-5d3a-4e7b-9f8c-1a2b3c4d5e6f";
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/issues/WEB-123/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({"id": "iss-9", "project": project, "sequence_id": 123}),
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let resolved = issue(&client, "test-ws", Some(project), "web-123")
+            .await
+            .unwrap();
+        assert_eq!(resolved.project, project);
+        assert_eq!(resolved.id, "iss-9");
+    }
+
+    #[tokio::test]
+    async fn test_issue_identifier_from_other_project_fails() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/issues/API-7/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({"id": "iss-7", "project": "proj-api", "sequence_id": 7}),
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let err = issue(
+            &client,
+            "test-ws",
+            Some("0b6f2c1e-5d3a-4e7b-9f8c-1a2b3c4d5e6f"),
+            "API-7",
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("issue API-7 is not in project"),
+            "{err:#}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_issue_identifier_uses_workspace_lookup() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/workspaces/test-ws/issues/WEB-123/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({"id": "iss-9", "project": "proj9", "sequence_id": 123}),
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = test_client(&mock_server.uri());
+        let resolved = issue(&client, "test-ws", None, "web-123").await.unwrap();
+        assert_eq!(resolved.project, "proj9");
+        assert_eq!(resolved.id, "iss-9");
+    }
+
+    #[tokio::test]
+    async fn test_issue_uuid_without_project_fails() {
+        let client = test_client("http://127.0.0.1:9");
+        let err = issue(
+            &client,
+            "test-ws",
+            None,
+            "2f1c7a52-6a43-4b65-9c1e-3d2a9f0b8e71",
+        )
+        .await
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("project is required"));
+    }
+}
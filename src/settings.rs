@@ -8,6 +8,18 @@ pub struct Settings {
     pub base_url: String,
     pub workspace: Option<String>,
     pub timeout: u64,
+    /// How many times a failed request is retried before giving up.
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries, in milliseconds.
+    pub retry_backoff_ms: u64,
+    /// Upper bound for a single backoff delay, in milliseconds.
+    pub retry_max_backoff_ms: u64,
+    /// Randomize backoff delays so concurrent clients don't retry in lockstep.
+    pub retry_jitter: bool,
+    /// Also retry non-idempotent requests (POST, PATCH) on 5xx and connection errors.
+    pub retry_non_idempotent: bool,
+    /// Client-side request budget per minute (0 disables throttling).
+    pub rate_limit: u32,
 }
 
 impl Default for Settings {
@@ -17,6 +29,12 @@ impl Default for Settings {
             base_url: "https://api.plane.so".to_string(),
             workspace: None,
             timeout: 30,
+            max_retries: 3,
+            retry_backoff_ms: 500,
+            retry_max_backoff_ms: 30_000,
+            retry_jitter: true,
+            retry_non_idempotent: false,
+            rate_limit: 60,
         }
     }
 }
@@ -100,6 +118,11 @@ fn merge_env(base: &mut serde_json::Value) {
     {
         map.insert("timeout".to_string(), serde_json::json!(n));
     }
+    if let Ok(v) = std::env::var("PLANE_CLI_MAX_RETRIES")
+        && let Ok(n) = v.parse::<u32>()
+    {
+        map.insert("max_retries".to_string(), serde_json::json!(n));
+    }
 }
 
 fn merge_cli(base: &mut serde_json::Value, cli: CliOverrides) {
@@ -244,6 +267,17 @@ mod tests {
         );
     }
 
+    #[test]
+    #[serial]
+    fn test_merge_env_reads_max_retries() {
+        temp_env::with_vars([("PLANE_CLI_MAX_RETRIES", Some("7"))], || {
+            let mut base = serde_json::to_value(Settings::default()).unwrap();
+            merge_env(&mut base);
+
+            assert_eq!(base["max_retries"], 7);
+        });
+    }
+
     #[test]
     #[serial]
     fn test_merge_env_skips_unset_vars() {
@@ -253,6 +287,7 @@ mod tests {
                 ("PLANE_CLI_BASE_URL", None::<&str>),
                 ("PLANE_CLI_WORKSPACE", None::<&str>),
                 ("PLANE_CLI_TIMEOUT", None::<&str>),
+                ("PLANE_CLI_MAX_RETRIES", None::<&str>),
             ],
             || {
                 let mut base = serde_json::to_value(Settings::default()).unwrap();
@@ -326,11 +361,13 @@ mod tests {
                 ("PLANE_CLI_BASE_URL", None::<&str>),
                 ("PLANE_CLI_WORKSPACE", None::<&str>),
                 ("PLANE_CLI_TIMEOUT", None::<&str>),
+                ("PLANE_CLI_MAX_RETRIES", None::<&str>),
             ],
             || {
                 let s = Settings::load(empty_cli()).unwrap();
                 assert_eq!(s.base_url, "https://api.plane.so");
                 assert_eq!(s.timeout, 30);
+                assert_eq!(s.max_retries, 3);
                 assert!(s.api_key.is_none());
                 assert!(s.workspace.is_none());
             },
@@ -352,6 +389,7 @@ mod tests {
                 ("PLANE_CLI_BASE_URL", None::<&str>),
                 ("PLANE_CLI_WORKSPACE", None::<&str>),
                 ("PLANE_CLI_TIMEOUT", None::<&str>),
+                ("PLANE_CLI_MAX_RETRIES", None::<&str>),
             ],
             || {
                 let s = Settings::load(empty_cli()).unwrap();
@@ -381,6 +419,7 @@ mod tests {
                 ("PLANE_CLI_BASE_URL", None::<&str>),
                 ("PLANE_CLI_WORKSPACE", None::<&str>),
                 ("PLANE_CLI_TIMEOUT", None::<&str>),
+                ("PLANE_CLI_MAX_RETRIES", None::<&str>),
             ],
             || {
                 let s = Settings::load(empty_cli()).unwrap();
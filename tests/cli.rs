@@ -1,8 +1,12 @@
 use assert_cmd::cargo::cargo_bin_cmd;
 use predicates::prelude::*;
-use wiremock::matchers::{method, path, query_param};
+use wiremock::matchers::{body_json, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
+const PROJECT: &str = "0b6f2c1e-5d3a-4e7b-9f8c-1a2b3c4d5e6f";
+const STATE: &str = "7c1d9e2a-3b4f-4a5c-8d6e-0f1a2b3c4d5e";
+const MEMBER: &str = "4e2a8b1c-9d3f-4c6a-b7e5-2d1c0b9a8f7e";
+
 fn plane_cmd() -> assert_cmd::Command {
     cargo_bin_cmd!("plane-cli")
 }
@@ -107,15 +111,15 @@ async fn api_401_shows_unauthorized() {
 async fn api_404_shows_not_found() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path(
-            "/api/v1/workspaces/test-ws/projects/proj1/issues/bad-id/",
-        ))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/bad-id/"
+        )))
         .respond_with(ResponseTemplate::new(404).set_body_string("not found"))
         .mount(&mock_server)
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["issues", "get", "--project", "proj1", "--id", "bad-id"])
+        .args(["issues", "get", "--project", PROJECT, "--id", "bad-id"])
         .assert()
         .failure()
         .stderr(predicate::str::contains("not found"));
@@ -189,25 +193,29 @@ async fn projects_list_empty() {
 async fn issues_list_json() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/issues/"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
         .and(query_param("per_page", "50"))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
             "results": [
-                {"id": "iss-1", "sequence_id": 1, "name": "Bug A", "priority": "high"},
-                {"id": "iss-2", "sequence_id": 2, "name": "Bug B", "priority": "low"}
+                {"id": "1a1a1a1a-0000-4000-8000-000000000001", "sequence_id": 1, "name": "Bug A", "priority": "high"},
+                {"id": "2b2b2b2b-0000-4000-8000-000000000002", "sequence_id": 2, "name": "Bug B", "priority": "low"}
             ]
         })))
         .mount(&mock_server)
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["--json", "issues", "list", "--project", "proj1"])
+        .args(["--json", "issues", "list", "--project", PROJECT])
         .assert()
         .success()
         .stdout(
             predicate::str::contains("Bug A")
                 .and(predicate::str::contains("Bug B"))
-                .and(predicate::str::contains("iss-1")),
+                .and(predicate::str::contains(
+                    "1a1a1a1a-0000-4000-8000-000000000001",
+                )),
         );
 }
 
@@ -215,17 +223,19 @@ async fn issues_list_json() {
 async fn issues_list_table() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/issues/"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
             "results": [
-                {"id": "iss-1", "sequence_id": 1, "name": "Bug A", "priority": "high"}
+                {"id": "1a1a1a1a-0000-4000-8000-000000000001", "sequence_id": 1, "name": "Bug A", "priority": "high"}
             ]
         })))
         .mount(&mock_server)
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["issues", "list", "--project", "proj1"])
+        .args(["issues", "list", "--project", PROJECT])
         .assert()
         .success()
         .stdout(
@@ -239,9 +249,11 @@ async fn issues_list_table() {
 async fn issues_list_with_filters() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/issues/"))
-        .and(query_param("state", "state-1"))
-        .and(query_param("assignee", "user-1"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(query_param("state", STATE))
+        .and(query_param("assignee", MEMBER))
         .and(query_param("per_page", "10"))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"results": []})))
         .expect(1)
@@ -253,11 +265,11 @@ async fn issues_list_with_filters() {
             "issues",
             "list",
             "--project",
-            "proj1",
+            PROJECT,
             "--state",
-            "state-1",
+            STATE,
             "--assignee",
-            "user-1",
+            MEMBER,
             "--per-page",
             "10",
         ])
@@ -265,17 +277,128 @@ async fn issues_list_with_filters() {
         .success();
 }
 
+#[tokio::test]
+async fn issues_list_table_shows_next_cursor() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "1a1a1a1a-0000-4000-8000-000000000001", "sequence_id": 1, "name": "Bug A", "priority": "high"}
+            ],
+            "next_cursor": "50:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--cursor 50:1:0"));
+}
+
+#[tokio::test]
+async fn issues_list_all_json_merges_pages() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(query_param("cursor", "1:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "2b2b2b2b-0000-4000-8000-000000000002", "sequence_id": 2, "name": "Bug B", "priority": "low"}
+            ],
+            "next_cursor": "1:2:0",
+            "next_page_results": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(query_param("per_page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "1a1a1a1a-0000-4000-8000-000000000001", "sequence_id": 1, "name": "Bug A", "priority": "high"}
+            ],
+            "next_cursor": "1:1:0",
+            "next_page_results": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args([
+            "--json",
+            "issues",
+            "list",
+            "--project",
+            PROJECT,
+            "--per-page",
+            "1",
+            "--all",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let data: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(data["count"], 2);
+    assert_eq!(
+        data["results"][0]["id"],
+        "1a1a1a1a-0000-4000-8000-000000000001"
+    );
+    assert_eq!(
+        data["results"][1]["id"],
+        "2b2b2b2b-0000-4000-8000-000000000002"
+    );
+}
+
+#[tokio::test]
+async fn projects_list_limit_truncates() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/projects/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "p1", "name": "Alpha", "identifier": "ALP"},
+                {"id": "p2", "name": "Beta", "identifier": "BET"}
+            ],
+            "next_cursor": "2:1:0",
+            "next_page_results": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["projects", "list", "--limit", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Alpha").and(predicate::str::contains("Beta").not()));
+}
+
 // ── Issues get ──
 
 #[tokio::test]
 async fn issues_get_json() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path(
-            "/api/v1/workspaces/test-ws/projects/proj1/issues/iss-1/",
-        ))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "id": "iss-1",
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
             "sequence_id": 42,
             "name": "Login Bug",
             "priority": "urgent",
@@ -294,15 +417,17 @@ async fn issues_get_json() {
             "issues",
             "get",
             "--project",
-            "proj1",
+            PROJECT,
             "--id",
-            "iss-1",
+            "1a1a1a1a-0000-4000-8000-000000000001",
         ])
         .assert()
         .success()
         .stdout(
             predicate::str::contains("Login Bug")
-                .and(predicate::str::contains("iss-1"))
+                .and(predicate::str::contains(
+                    "1a1a1a1a-0000-4000-8000-000000000001",
+                ))
                 .and(predicate::str::contains("urgent")),
         );
 }
@@ -311,11 +436,11 @@ async fn issues_get_json() {
 async fn issues_get_table() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path(
-            "/api/v1/workspaces/test-ws/projects/proj1/issues/iss-1/",
-        ))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "id": "iss-1",
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
             "sequence_id": 42,
             "name": "Login Bug",
             "priority": "urgent",
@@ -329,7 +454,14 @@ async fn issues_get_table() {
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["issues", "get", "--project", "proj1", "--id", "iss-1"])
+        .args([
+            "issues",
+            "get",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+        ])
         .assert()
         .success()
         .stdout(
@@ -339,15 +471,128 @@ async fn issues_get_table() {
         );
 }
 
+async fn mount_issue_with_description(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "sequence_id": 42,
+            "name": "Login Bug",
+            "description_html": "<h2>Steps</h2><ul><li><p>Open <a href=\"https://x.io\">app</a></p></li></ul>"
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn issues_get_renders_description() {
+    let mock_server = MockServer::start().await;
+    mount_issue_with_description(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "get",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("  Steps\n")
+                .and(predicate::str::contains("- Open app (https://x.io)"))
+                .and(predicate::str::contains("<").not()),
+        );
+}
+
+#[tokio::test]
+async fn issues_get_format_markdown() {
+    let mock_server = MockServer::start().await;
+    mount_issue_with_description(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "get",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--format",
+            "markdown",
+        ])
+        .assert()
+        .success()
+        .stdout("## Steps\n\n- Open [app](https://x.io)\n");
+}
+
+#[tokio::test]
+async fn issues_get_by_identifier() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/issues/WEB-42/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "project": PROJECT,
+            "sequence_id": 42
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "sequence_id": 42,
+            "name": "Login Bug",
+            "priority": "urgent"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "get", "WEB-42"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Login Bug"));
+}
+
+#[test]
+fn issues_get_uuid_without_project_fails() {
+    plane_cmd()
+        .env("PLANE_CLI_HOME", "/tmp/plane-cli-test-nonexistent")
+        .args([
+            "--api-key",
+            "test",
+            "--workspace",
+            "ws",
+            "issues",
+            "get",
+            "2f1c7a52-6a43-4b65-9c1e-3d2a9f0b8e71",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("project is required"));
+}
+
 // ── Issues create ──
 
 #[tokio::test]
 async fn issues_create_json() {
     let mock_server = MockServer::start().await;
     Mock::given(method("POST"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/issues/"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "id": "iss-1",
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
             "sequence_id": 42,
             "name": "New Bug"
         })))
@@ -360,22 +605,26 @@ async fn issues_create_json() {
             "issues",
             "create",
             "--project",
-            "proj1",
+            PROJECT,
             "--title",
             "New Bug",
         ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("iss-1"));
+        .stdout(predicate::str::contains(
+            "1a1a1a1a-0000-4000-8000-000000000001",
+        ));
 }
 
 #[tokio::test]
 async fn issues_create_table() {
     let mock_server = MockServer::start().await;
     Mock::given(method("POST"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/issues/"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "id": "iss-1",
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
             "sequence_id": 42,
             "name": "New Bug"
         })))
@@ -387,7 +636,7 @@ async fn issues_create_table() {
             "issues",
             "create",
             "--project",
-            "proj1",
+            PROJECT,
             "--title",
             "New Bug",
         ])
@@ -400,131 +649,3615 @@ async fn issues_create_table() {
         );
 }
 
-// ── States ──
-
 #[tokio::test]
-async fn states_list_json() {
+async fn issues_create_resolves_names() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/states/"))
+        .and(path("/api/v1/workspaces/test-ws/projects/"))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "results": [
-                {"id": "s1", "name": "Todo", "group": "backlog"},
-                {"id": "s2", "name": "In Progress", "group": "started"}
-            ]
+            "results": [{"id": PROJECT, "name": "Website", "identifier": "WEB"}]
         })))
         .mount(&mock_server)
         .await;
-
-    plane_cmd_with(&mock_server.uri())
-        .args(["--json", "states", "list", "--project", "proj1"])
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("Todo").and(predicate::str::contains("In Progress")));
-}
-
-#[tokio::test]
-async fn states_list_table() {
-    let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/states/"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "results": [
-                {"id": "s1", "name": "Todo", "group": "backlog"}
-            ]
+            "results": [{"id": STATE, "name": "In Progress", "group": "started"}]
         })))
         .mount(&mock_server)
         .await;
-
-    plane_cmd_with(&mock_server.uri())
-        .args(["states", "list", "--project", "proj1"])
-        .assert()
-        .success()
-        .stdout(
-            predicate::str::contains("Todo")
-                .and(predicate::str::contains("backlog"))
-                .and(predicate::str::contains("Group")),
-        );
-}
-
-// ── Labels ──
-
-#[tokio::test]
-async fn labels_list_json() {
-    let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/labels/"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "results": [
-                {"id": "l1", "name": "bug"},
-                {"id": "l2", "name": "feature"}
-            ]
+            "results": [{"id": "l1", "name": "bug"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": MEMBER, "display_name": "alice", "email": "alice@example.com"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(body_json(serde_json::json!({
+            "name": "New Bug",
+            "state": STATE,
+            "assignees": [MEMBER],
+            "labels": ["l1"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "sequence_id": 42,
+            "name": "New Bug"
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["--json", "labels", "list", "--project", "proj1"])
+        .args([
+            "issues",
+            "create",
+            "-p",
+            "web",
+            "--title",
+            "New Bug",
+            "--state",
+            "in progress",
+            "--assignee",
+            "alice@example.com",
+            "--label",
+            "bug",
+        ])
         .assert()
         .success()
-        .stdout(predicate::str::contains("bug").and(predicate::str::contains("feature")));
+        .stdout(predicate::str::contains("Created"));
 }
 
 #[tokio::test]
-async fn labels_list_table() {
+async fn issues_create_unknown_state_suggests() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/labels/"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/"
+        )))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
             "results": [
-                {"id": "l1", "name": "bug"}
+                {"id": "s1", "name": "Todo"},
+                {"id": "s2", "name": "In Progress"}
             ]
         })))
         .mount(&mock_server)
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["labels", "list", "--project", "proj1"])
+        .args([
+            "issues", "create", "-p", PROJECT, "--title", "New Bug", "--state", "progress",
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("bug").and(predicate::str::contains("Name")));
+        .failure()
+        .stderr(
+            predicate::str::contains("unknown state 'progress'")
+                .and(predicate::str::contains("did you mean: In Progress")),
+        );
 }
 
-// ── Members ──
-
 #[tokio::test]
-async fn members_list_json() {
+async fn issues_create_with_markdown_description() {
     let mock_server = MockServer::start().await;
-    Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/members/"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
-            {"id": "m1", "display_name": "Alice"},
-            {"id": "m2", "display_name": "Bob"}
-        ])))
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(body_json(serde_json::json!({
+            "name": "New Bug",
+            "description_html": "<p>Steps:</p>\n<ul data-type=\"taskList\">\n<li data-type=\"taskItem\" data-checked=\"false\">reproduce</li>\n</ul>"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "sequence_id": 42,
+            "name": "New Bug"
+        })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["--json", "members", "list", "--project", "proj1"])
+        .args([
+            "issues",
+            "create",
+            "-p",
+            PROJECT,
+            "--title",
+            "New Bug",
+            "--description-md",
+            "Steps:\n\n- [ ] reproduce",
+        ])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Alice").and(predicate::str::contains("Bob")));
+        .success();
 }
 
 #[tokio::test]
-async fn members_list_table() {
+async fn issues_update_description_from_md_file() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let file = dir.path().join("desc.md");
+    std::fs::write(&file, "# Plan\n\nShip it").unwrap();
+
     let mock_server = MockServer::start().await;
-    Mock::given(method("GET"))
-        .and(path("/api/v1/workspaces/test-ws/projects/proj1/members/"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
-            {"id": "m1", "display_name": "Alice"}
-        ])))
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
+        .and(body_json(serde_json::json!({
+            "description_html": "<h1>Plan</h1>\n<p>Ship it</p>"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "sequence_id": 42,
+            "name": "Login Bug"
+        })))
+        .expect(1)
         .mount(&mock_server)
         .await;
 
     plane_cmd_with(&mock_server.uri())
-        .args(["members", "list", "--project", "proj1"])
+        .args([
+            "issues",
+            "update",
+            "-p",
+            PROJECT,
+            "-i",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--description-file",
+        ])
+        .arg(&file)
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Alice").and(predicate::str::contains("Name")));
+        .success();
+}
+
+#[test]
+fn issues_create_rejects_multiple_description_sources() {
+    plane_cmd()
+        .args([
+            "--api-key",
+            "test",
+            "--workspace",
+            "ws",
+            "issues",
+            "create",
+            "-p",
+            PROJECT,
+            "--title",
+            "t",
+            "--description",
+            "<p>a</p>",
+            "--description-md",
+            "a",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+// ── Issues update ──
+
+#[tokio::test]
+async fn issues_update_sends_only_given_fields() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
+        .and(body_json(serde_json::json!({
+            "name": "Renamed",
+            "priority": "high"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "sequence_id": 42,
+            "name": "Renamed",
+            "priority": "high"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "update",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--title",
+            "Renamed",
+            "--priority",
+            "high",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Updated")
+                .and(predicate::str::contains("#42"))
+                .and(predicate::str::contains("priority:"))
+                .and(predicate::str::contains("high")),
+        );
+}
+
+#[tokio::test]
+async fn issues_update_json() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "1a1a1a1a-0000-4000-8000-000000000001",
+            "sequence_id": 42,
+            "name": "Login Bug",
+            "target_date": "2025-02-01"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "--json",
+            "issues",
+            "update",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--target-date",
+            "2025-02-01",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"target_date\": \"2025-02-01\""));
+}
+
+#[test]
+fn issues_update_without_fields_fails() {
+    plane_cmd()
+        .env("PLANE_CLI_HOME", "/tmp/plane-cli-test-nonexistent")
+        .args([
+            "--api-key",
+            "test",
+            "--workspace",
+            "ws",
+            "issues",
+            "update",
+            "--project",
+            PROJECT,
+            "--id",
+            "i1",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("nothing to update"));
+}
+
+// ── Issues delete / archive ──
+
+#[tokio::test]
+async fn issues_delete_requires_yes_without_tty() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "delete",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--yes"));
+}
+
+#[tokio::test]
+async fn issues_delete_with_yes() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "delete",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--yes",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Deleted").and(predicate::str::contains(
+                "1a1a1a1a-0000-4000-8000-000000000001",
+            )),
+        );
+}
+
+#[tokio::test]
+async fn issues_archive_with_yes_json() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/archive/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "--json",
+            "issues",
+            "archive",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--yes",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"archived\": true"));
+}
+
+#[tokio::test]
+async fn issues_unarchive() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/archive/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "unarchive",
+            "--project",
+            PROJECT,
+            "--id",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unarchived"));
+}
+
+// ── Issues comments ──
+
+#[tokio::test]
+async fn comments_list_table() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/comments/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{
+                "id": "c1",
+                "comment_html": "<p>Fixed in <strong>PR #7</strong></p>",
+                "actor_detail": {"display_name": "alice"},
+                "created_at": "2020-01-01T00:00:00Z"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "comments",
+            "list",
+            "-p",
+            PROJECT,
+            "-i",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("alice")
+                .and(predicate::str::contains("Fixed in PR #7"))
+                .and(predicate::str::contains("2020-01-01"))
+                .and(predicate::str::contains("<p>").not()),
+        );
+}
+
+#[tokio::test]
+async fn comments_add_from_flag() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/comments/"
+        )))
+        .and(body_json(serde_json::json!({
+            "comment_html": "<p>Done &amp; shipped</p>"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "c1"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "comments",
+            "add",
+            "-p",
+            PROJECT,
+            "-i",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--body",
+            "Done & shipped",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added").and(predicate::str::contains("c1")));
+}
+
+#[tokio::test]
+async fn comments_add_from_stdin() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/comments/"
+        )))
+        .and(body_json(serde_json::json!({
+            "comment_html": "<p><strong>Deployed</strong> to staging</p>"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "c1"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "comments",
+            "add",
+            "-p",
+            PROJECT,
+            "-i",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+        ])
+        .write_stdin("**Deployed** to staging\n")
+        .assert()
+        .success();
+}
+
+#[tokio::test]
+async fn comments_delete_with_yes() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/1a1a1a1a-0000-4000-8000-000000000001/comments/c1/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "comments",
+            "delete",
+            "-p",
+            PROJECT,
+            "-i",
+            "1a1a1a1a-0000-4000-8000-000000000001",
+            "--comment",
+            "c1",
+            "--yes",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Deleted comment c1"));
+}
+
+// ── States ──
+
+#[tokio::test]
+async fn states_list_json() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "s1", "name": "Todo", "group": "backlog"},
+                {"id": "s2", "name": "In Progress", "group": "started"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["--json", "states", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Todo").and(predicate::str::contains("In Progress")));
+}
+
+#[tokio::test]
+async fn states_list_table() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "s1", "name": "Todo", "group": "backlog"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["states", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Todo")
+                .and(predicate::str::contains("backlog"))
+                .and(predicate::str::contains("Group")),
+        );
+}
+
+// ── Labels ──
+
+#[tokio::test]
+async fn labels_list_json() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "l1", "name": "bug"},
+                {"id": "l2", "name": "feature"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["--json", "labels", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("bug").and(predicate::str::contains("feature")));
+}
+
+#[tokio::test]
+async fn labels_list_table() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "l1", "name": "bug"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["labels", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("bug").and(predicate::str::contains("Name")));
+}
+
+// ── Members ──
+
+#[tokio::test]
+async fn members_list_json() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "m1", "display_name": "Alice"},
+            {"id": "m2", "display_name": "Bob"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["--json", "members", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Alice").and(predicate::str::contains("Bob")));
+}
+
+#[tokio::test]
+async fn members_list_table() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "m1", "display_name": "Alice"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["members", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Alice").and(predicate::str::contains("Name")));
+}
+
+// ── Cycles ──
+
+const CYCLE: &str = "5a6b7c8d-9e0f-4a1b-8c2d-3e4f5a6b7c8d";
+const ISSUE: &str = "9f8e7d6c-5b4a-4392-8170-6f5e4d3c2b1a";
+
+#[tokio::test]
+async fn cycles_list_table_shows_status_and_counts() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/cycles/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {
+                    "id": "c1",
+                    "name": "Sprint 1",
+                    "start_date": "2020-01-06",
+                    "end_date": "2020-01-17",
+                    "total_issues": 10,
+                    "completed_issues": 8,
+                    "cancelled_issues": 2
+                },
+                {
+                    "id": "c2",
+                    "name": "Sprint 99",
+                    "start_date": "2999-01-06T00:00:00Z",
+                    "end_date": "2999-01-17T00:00:00Z",
+                    "total_issues": 3,
+                    "backlog_issues": 3
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["cycles", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("completed")
+                .and(predicate::str::contains("upcoming"))
+                .and(predicate::str::contains("2020-01-17"))
+                .and(predicate::str::contains("8/10 done · 2 cancelled"))
+                .and(predicate::str::contains("0/3 done · 3 backlog")),
+        );
+}
+
+#[tokio::test]
+async fn cycles_get_by_name() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/cycles/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": CYCLE, "name": "Sprint 1"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/cycles/{CYCLE}/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": CYCLE,
+            "name": "Sprint 1",
+            "start_date": "2020-01-06",
+            "end_date": "2020-01-17",
+            "total_issues": 4,
+            "started_issues": 1,
+            "completed_issues": 3
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["cycles", "get", "sprint 1", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Sprint 1")
+                .and(predicate::str::contains("(completed)"))
+                .and(predicate::str::contains("started:   1")),
+        );
+}
+
+#[tokio::test]
+async fn cycles_create_sends_dates() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/cycles/"
+        )))
+        .and(body_json(serde_json::json!({
+            "name": "Sprint 2",
+            "start_date": "2025-01-06",
+            "end_date": "2025-01-17"
+        })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({"id": CYCLE, "name": "Sprint 2"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "cycles",
+            "create",
+            "--project",
+            PROJECT,
+            "--name",
+            "Sprint 2",
+            "--start-date",
+            "2025-01-06",
+            "--end-date",
+            "2025-01-17",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created cycle Sprint 2"));
+}
+
+#[tokio::test]
+async fn cycles_update_requires_a_field() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args(["cycles", "update", CYCLE, "--project", PROJECT])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("nothing to update"));
+}
+
+#[tokio::test]
+async fn cycles_delete_requires_yes_without_tty() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args(["cycles", "delete", CYCLE, "--project", PROJECT])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--yes"));
+}
+
+#[tokio::test]
+async fn cycles_add_issues_resolves_identifiers() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/issues/WEB-7/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "7c7c7c7c-0000-4000-8000-000000000007",
+            "project": PROJECT
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/cycles/{CYCLE}/cycle-issues/"
+        )))
+        .and(body_json(
+            serde_json::json!({ "issues": ["7c7c7c7c-0000-4000-8000-000000000007", ISSUE] }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "cycles",
+            "add-issues",
+            CYCLE,
+            "WEB-7",
+            ISSUE,
+            "--project",
+            PROJECT,
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added 2 issues"));
+}
+
+#[tokio::test]
+async fn cycles_remove_issue() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/cycles/{CYCLE}/cycle-issues/{ISSUE}/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "--json",
+            "cycles",
+            "remove-issue",
+            CYCLE,
+            ISSUE,
+            "-p",
+            PROJECT,
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"removed\": true"));
+}
+
+// ── Modules ──
+
+const MODULE: &str = "2b3c4d5e-6f7a-4b8c-9d0e-1f2a3b4c5d6e";
+
+#[tokio::test]
+async fn modules_list_table() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/modules/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{
+                "id": "m1",
+                "name": "Billing",
+                "status": "in-progress",
+                "lead": MEMBER,
+                "lead_detail": {"id": MEMBER, "display_name": "alice"},
+                "members": [MEMBER, "u2"],
+                "members_detail": [
+                    {"id": MEMBER, "display_name": "alice"},
+                    {"id": "u2", "display_name": "bob"}
+                ],
+                "target_date": "2025-03-31",
+                "total_issues": 5,
+                "completed_issues": 2,
+                "started_issues": 3
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["modules", "list", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Billing")
+                .and(predicate::str::contains("in-progress"))
+                .and(predicate::str::contains("alice, bob"))
+                .and(predicate::str::contains("2025-03-31"))
+                .and(predicate::str::contains("2/5 done · 3 started")),
+        );
+}
+
+#[tokio::test]
+async fn modules_create_resolves_lead_and_members() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": MEMBER, "display_name": "alice", "email": "alice@example.com"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/modules/"
+        )))
+        .and(body_json(serde_json::json!({
+            "name": "Billing",
+            "status": "in-progress",
+            "lead": MEMBER,
+            "members": [MEMBER],
+            "target_date": "2025-03-31"
+        })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({"id": MODULE, "name": "Billing"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "modules",
+            "create",
+            "--project",
+            PROJECT,
+            "--name",
+            "Billing",
+            "--status",
+            "in-progress",
+            "--lead",
+            "alice",
+            "--member",
+            "alice@example.com",
+            "--target-date",
+            "2025-03-31",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created module Billing"));
+}
+
+#[tokio::test]
+async fn modules_update_by_name() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/modules/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": MODULE, "name": "Billing"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/modules/{MODULE}/"
+        )))
+        .and(body_json(serde_json::json!({ "status": "completed" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": MODULE,
+            "name": "Billing",
+            "status": "completed"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "modules",
+            "update",
+            "billing",
+            "--project",
+            PROJECT,
+            "--status",
+            "completed",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Updated module Billing")
+                .and(predicate::str::contains("status: completed")),
+        );
+}
+
+#[tokio::test]
+async fn modules_add_and_remove_issues() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/modules/{MODULE}/module-issues/"
+        )))
+        .and(body_json(serde_json::json!({ "issues": [ISSUE] })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/modules/{MODULE}/module-issues/{ISSUE}/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["modules", "add-issues", MODULE, ISSUE, "-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added 1 issue to module"));
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["modules", "remove-issue", MODULE, ISSUE, "-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed issue"));
+}
+
+// ── Sub-issues ──
+
+fn tree_issues() -> serde_json::Value {
+    serde_json::json!({
+        "results": [
+            {"id": "i1", "sequence_id": 1, "name": "Backend rewrite", "state": STATE, "priority": "high", "parent": null},
+            {"id": "i3", "sequence_id": 3, "name": "API", "state": STATE, "priority": "none", "parent": "i1"},
+            {"id": "i2", "sequence_id": 2, "name": "Schema", "state": STATE, "priority": "medium", "parent": "i1"},
+            {"id": "i4", "sequence_id": 4, "name": "Migrations", "state": STATE, "priority": "low", "parent": "i2"},
+            {"id": "i5", "sequence_id": 5, "name": "Docs", "state": STATE, "priority": "none"}
+        ],
+        "next_page_results": false
+    })
+}
+
+async fn mount_tree(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tree_issues()))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": STATE, "name": "Todo", "group": "unstarted"}]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn issues_tree_renders_hierarchy() {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "tree", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            "#1 Backend rewrite  Todo · high\n\
+             ├── #2 Schema  Todo · medium\n\
+             │   └── #4 Migrations  Todo · low\n\
+             └── #3 API  Todo · none\n\
+             #5 Docs  Todo · none\n",
+        );
+}
+
+#[tokio::test]
+async fn issues_tree_json_is_nested() {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["--json", "issues", "tree", "i2", "--project", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let tree: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(tree[0]["name"], "Schema");
+    assert_eq!(tree[0]["children"][0]["name"], "Migrations");
+    assert_eq!(tree[0]["children"][0]["children"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn issues_children_table() {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/i1/sub-issues/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sub_issues": [
+                {"id": "i2", "sequence_id": 2, "name": "Schema", "state": STATE, "priority": "medium"}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "children", "i1", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Schema")
+                .and(predicate::str::contains("Todo"))
+                .and(predicate::str::contains("medium")),
+        );
+}
+
+#[tokio::test]
+async fn issues_children_names_states_past_first_page() {
+    let mock_server = MockServer::start().await;
+    let states = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}/states/");
+    Mock::given(method("GET"))
+        .and(path(states.as_str()))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": OTHER_STATE, "name": "Shipped"}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(states.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": STATE, "name": "Todo"}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/i1/sub-issues/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sub_issues": [
+                {"id": "i2", "sequence_id": 2, "name": "Schema", "state": OTHER_STATE}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "children", "i1", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Shipped"));
+}
+
+#[tokio::test]
+async fn issues_get_lists_sub_issues() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/i1/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "i1",
+            "sequence_id": 1,
+            "name": "Backend rewrite"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/i1/sub-issues/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sub_issues": [{"id": "i2", "sequence_id": 2, "name": "Schema"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "get", "i1", "--project", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("sub-issues:\n    #2 Schema"));
+}
+
+#[tokio::test]
+async fn issues_create_with_parent_identifier() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/issues/WEB-1/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "i1",
+            "project": PROJECT
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(body_json(
+            serde_json::json!({ "name": "Schema", "parent": "i1" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "i2",
+            "sequence_id": 2,
+            "name": "Schema"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues", "create", "-p", PROJECT, "--title", "Schema", "--parent", "WEB-1",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created #2 Schema"));
+}
+
+// ── Relations ──
+
+#[tokio::test]
+async fn issues_relate_resolves_each_relation_type() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/issues/API-9/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "api-9",
+            "project": "other-project"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-relation/"
+        )))
+        .and(body_json(serde_json::json!({
+            "relation_type": "blocked_by",
+            "issues": ["api-9"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-relation/"
+        )))
+        .and(body_json(serde_json::json!({
+            "relation_type": "relates_to",
+            "issues": ["2b2b2b2b-0000-4000-8000-000000000002"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "relate",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--blocked-by",
+            "API-9",
+            "--relates-to",
+            "2b2b2b2b-0000-4000-8000-000000000002",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("blocked-by api-9").and(predicate::str::contains(
+                "relates-to 2b2b2b2b-0000-4000-8000-000000000002",
+            )),
+        );
+}
+
+#[tokio::test]
+async fn issues_relate_requires_a_relation() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args(["issues", "relate", ISSUE, "-p", PROJECT])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no relations given"));
+}
+
+#[tokio::test]
+async fn issues_unrelate_posts_remove_relation() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/remove-relation/"
+        )))
+        .and(body_json(serde_json::json!({
+            "relation_type": "blocking",
+            "related_issue": "2b2b2b2b-0000-4000-8000-000000000002"
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "unrelate",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--blocks",
+            "2b2b2b2b-0000-4000-8000-000000000002",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unrelated"));
+}
+
+#[tokio::test]
+async fn issues_relations_and_get_show_relations() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-relation/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "blocking": [],
+            "blocked_by": [
+                {"id": "api-9", "sequence_id": 9, "name": "Auth endpoint", "project_identifier": "API"}
+            ],
+            "duplicate": [],
+            "relates_to": [{"id": "2b2b2b2b-0000-4000-8000-000000000002", "sequence_id": 2, "name": "Login page"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": ISSUE,
+            "sequence_id": 1,
+            "name": "Login form"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "relations", ISSUE, "-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("blocked-by")
+                .and(predicate::str::contains("API-9 Auth endpoint"))
+                .and(predicate::str::contains("#2 Login page")),
+        );
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "get", ISSUE, "-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "relations:\n    blocked-by   API-9 Auth endpoint",
+        ));
+}
+
+// ── Links ──
+
+#[tokio::test]
+async fn links_add_with_title() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/links/"
+        )))
+        .and(body_json(serde_json::json!({
+            "url": "https://github.com/acme/web/pull/7",
+            "title": "PR #7"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": "link-1",
+            "url": "https://github.com/acme/web/pull/7"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "links",
+            "add",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--url",
+            "https://github.com/acme/web/pull/7",
+            "--title",
+            "PR #7",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added link").and(predicate::str::contains("link-1")));
+}
+
+async fn mount_links(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/links/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "link-1", "url": "https://github.com/acme/web/pull/7", "title": "PR #7"}
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn links_list_table() {
+    let mock_server = MockServer::start().await;
+    mount_links(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "links", "list", ISSUE, "-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("PR #7").and(predicate::str::contains(
+                "https://github.com/acme/web/pull/7",
+            )),
+        );
+}
+
+#[tokio::test]
+async fn links_remove_by_url() {
+    let mock_server = MockServer::start().await;
+    mount_links(&mock_server).await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/links/link-1/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "links",
+            "remove",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--link",
+            "https://github.com/acme/web/pull/7",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed link"));
+}
+
+#[tokio::test]
+async fn links_remove_unknown_url_fails() {
+    let mock_server = MockServer::start().await;
+    mount_links(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "links",
+            "remove",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--link",
+            "https://example.com/nope",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no link with URL"));
+}
+
+#[tokio::test]
+async fn issues_get_shows_links() {
+    let mock_server = MockServer::start().await;
+    mount_links(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": ISSUE,
+            "sequence_id": 1,
+            "name": "Login form"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "get", ISSUE, "-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "links:\n    PR #7 https://github.com/acme/web/pull/7",
+        ));
+}
+
+// ── Attachments ──
+
+#[tokio::test]
+async fn attachments_upload_uses_presigned_flow() {
+    let mock_server = MockServer::start().await;
+    let base =
+        format!("/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-attachments/");
+    Mock::given(method("POST"))
+        .and(path(base.clone()))
+        .and(body_json(serde_json::json!({
+            "name": "build.log",
+            "type": "text/plain",
+            "size": 11
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "upload_data": {
+                "url": format!("{}/storage", mock_server.uri()),
+                "fields": {"key": "attachments/build.log"}
+            },
+            "asset_id": "asset-1",
+            "attachment": {"id": "asset-1"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/storage"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("{base}asset-1/")))
+        .and(body_json(serde_json::json!({ "is_uploaded": true })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("build.log");
+    std::fs::write(&file, "build ok!\n\n").unwrap();
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "attachments",
+            "upload",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--file",
+        ])
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Uploaded build.log (11 B)"));
+}
+
+async fn mount_attachments(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-attachments/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": "asset-1",
+                "attributes": {"name": "screenshot.png", "size": 2048, "type": "image/png"},
+                "created_at": "2020-01-01T00:00:00Z"
+            }
+        ])))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn attachments_list_table() {
+    let mock_server = MockServer::start().await;
+    mount_attachments(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["issues", "attachments", "list", ISSUE, "-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("screenshot.png")
+                .and(predicate::str::contains("2.00 KiB"))
+                .and(predicate::str::contains("asset-1")),
+        );
+}
+
+#[tokio::test]
+async fn attachments_download_by_name() {
+    let mock_server = MockServer::start().await;
+    mount_attachments(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-attachments/asset-1/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"PNGDATA".to_vec()))
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "attachments",
+            "download",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--attachment",
+            "screenshot.png",
+            "--output",
+        ])
+        .arg(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Downloaded"));
+
+    let saved = std::fs::read(dir.path().join("screenshot.png")).unwrap();
+    assert_eq!(saved, b"PNGDATA");
+}
+
+async fn mount_named_attachment(mock_server: &MockServer, name: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-attachments/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "asset-1", "attributes": {"name": name, "size": 4}}
+        ])))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/issue-attachments/asset-1/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"DATA".to_vec()))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn attachments_download_strips_directories_from_name() {
+    let mock_server = MockServer::start().await;
+    mount_named_attachment(&mock_server, "../../evil.txt").await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let cwd = dir.path().join("work");
+    std::fs::create_dir(&cwd).unwrap();
+    plane_cmd_with(&mock_server.uri())
+        .current_dir(&cwd)
+        .args([
+            "issues",
+            "attachments",
+            "download",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--attachment",
+            "asset-1",
+        ])
+        .assert()
+        .success();
+
+    assert_eq!(std::fs::read(cwd.join("evil.txt")).unwrap(), b"DATA");
+    assert!(!dir.path().join("evil.txt").exists());
+    let entries: Vec<_> = std::fs::read_dir(&cwd).unwrap().collect();
+    assert_eq!(entries.len(), 1, "leftover files: {entries:?}");
+}
+
+#[tokio::test]
+async fn attachments_download_rejects_unusable_name() {
+    let mock_server = MockServer::start().await;
+    mount_named_attachment(&mock_server, "..").await;
+
+    let dir = tempfile::tempdir().unwrap();
+    plane_cmd_with(&mock_server.uri())
+        .current_dir(dir.path())
+        .args([
+            "issues",
+            "attachments",
+            "download",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--attachment",
+            "asset-1",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --output"));
+}
+
+#[tokio::test]
+async fn attachments_delete_unknown_name_fails() {
+    let mock_server = MockServer::start().await;
+    mount_attachments(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "attachments",
+            "delete",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--attachment",
+            "nope.png",
+            "--yes",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown attachment 'nope.png'"));
+}
+
+// ── Activity ──
+
+const LABEL: &str = "3c4d5e6f-7a8b-4c9d-8e0f-1a2b3c4d5e6f";
+
+async fn mount_activity(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/activities/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {
+                    "id": "a3", "created_at": "2025-01-05T10:00:00Z", "verb": "updated",
+                    "field": "labels", "old_value": "", "new_value": "",
+                    "new_identifier": LABEL, "actor": MEMBER
+                },
+                {
+                    "id": "a2", "created_at": "2025-01-03T10:00:00Z", "verb": "updated",
+                    "field": "state", "old_value": "Todo", "new_value": "In Progress",
+                    "actor": MEMBER
+                },
+                {
+                    "id": "a1", "created_at": "2025-01-01T10:00:00Z", "verb": "created",
+                    "field": null, "actor": MEMBER
+                }
+            ],
+            "next_page_results": false
+        })))
+        .mount(mock_server)
+        .await;
+    for (kind, body) in [
+        (
+            "states",
+            serde_json::json!({"results": [{"id": STATE, "name": "Todo"}]}),
+        ),
+        (
+            "labels",
+            serde_json::json!({"results": [{"id": LABEL, "name": "bug"}]}),
+        ),
+        (
+            "members",
+            serde_json::json!([{"id": MEMBER, "display_name": "alice"}]),
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/api/v1/workspaces/test-ws/projects/{PROJECT}/{kind}/"
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(mock_server)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn issues_activity_timeline_resolves_names() {
+    let mock_server = MockServer::start().await;
+    mount_activity(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["issues", "activity", ISSUE, "-p", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let created = stdout.find("created").unwrap();
+    let state = stdout.find("Todo → In Progress").unwrap();
+    let label = stdout.find("+ bug").unwrap();
+    assert!(
+        created < state && state < label,
+        "not chronological:\n{stdout}"
+    );
+    assert!(stdout.contains("alice"));
+    assert!(!stdout.contains(MEMBER));
+}
+
+#[tokio::test]
+async fn issues_activity_names_memberships_and_later_pages() {
+    let mock_server = MockServer::start().await;
+    let base = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}");
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/members/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "pm-1", "role": 15, "member": {"id": MEMBER, "display_name": "alice"}}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/labels/")))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": LABEL, "name": "bug"}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/labels/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "l-other", "name": "docs"}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_activity(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["issues", "activity", ISSUE, "-p", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("alice") && stdout.contains("+ bug"),
+        "{stdout}"
+    );
+    assert!(
+        !stdout.contains(MEMBER) && !stdout.contains(LABEL),
+        "{stdout}"
+    );
+}
+
+#[tokio::test]
+async fn issues_activity_since_filters_older_entries() {
+    let mock_server = MockServer::start().await;
+    mount_activity(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args([
+            "--json",
+            "issues",
+            "activity",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--since",
+            "2025-01-04",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let activities: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(activities.as_array().unwrap().len(), 1);
+    assert_eq!(activities[0]["id"], "a3");
+}
+
+#[test]
+fn issues_activity_rejects_bad_since() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args([
+            "issues",
+            "activity",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--since",
+            "yesterday",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid --since 'yesterday'"));
+}
+
+#[test]
+fn issues_activity_rejects_out_of_range_since() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args([
+            "issues",
+            "activity",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--since",
+            "999999999999999w",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "invalid --since '999999999999999w'",
+        ))
+        .stderr(predicate::str::contains("panicked").not());
+}
+
+// ── Worklogs ──
+
+#[tokio::test]
+async fn issues_worklog_add_parses_human_duration() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/worklogs/"
+        )))
+        .and(body_json(
+            serde_json::json!({"duration": 90, "description": "Code review"}),
+        ))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({"id": "w1", "duration": 90})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "issues",
+            "worklog",
+            "add",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--duration",
+            "1h30m",
+            "--description",
+            "Code review",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Logged 1h 30m"));
+}
+
+#[test]
+fn issues_worklog_add_rejects_bad_duration() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args([
+            "issues",
+            "worklog",
+            "add",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--duration",
+            "soon",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid duration 'soon'"));
+}
+
+async fn mount_worklogs(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/projects/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": PROJECT, "identifier": "WEB", "name": "Website"}]
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": ISSUE, "sequence_id": 7, "name": "Fix login"}],
+            "next_page_results": false
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/worklogs/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "w1", "duration": 90, "logged_by": MEMBER, "description": "Review",
+             "created_at": "2025-01-10T12:00:00Z"},
+            {"id": "w2", "duration": 30, "logged_by": MEMBER, "description": "Fix, retest",
+             "created_at": "2025-01-12T12:00:00Z"},
+            {"id": "w3", "duration": 60, "logged_by": MEMBER, "description": "Old",
+             "created_at": "2024-12-20T12:00:00Z"}
+        ])))
+        .mount(mock_server)
+        .await;
+    for (kind, body) in [
+        ("states", serde_json::json!({"results": []})),
+        ("labels", serde_json::json!({"results": []})),
+        (
+            "members",
+            serde_json::json!([{"id": MEMBER, "display_name": "alice"}]),
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/api/v1/workspaces/test-ws/projects/{PROJECT}/{kind}/"
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(mock_server)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn issues_worklog_list_shows_total() {
+    let mock_server = MockServer::start().await;
+    mount_worklogs(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["issues", "worklog", "list", ISSUE, "-p", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("alice"));
+    assert!(stdout.contains("1h 30m"));
+    assert!(stdout.contains("Total: 3h"), "{stdout}");
+}
+
+#[tokio::test]
+async fn issues_worklog_list_reads_every_page_and_names_memberships() {
+    let mock_server = MockServer::start().await;
+    let base = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}");
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/issues/{ISSUE}/worklogs/")))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "w2", "duration": 45, "logged_by": MEMBER,
+                         "created_at": "2025-01-12T12:00:00Z"}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/issues/{ISSUE}/worklogs/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "w1", "duration": 75, "logged_by": MEMBER,
+                         "created_at": "2025-01-10T12:00:00Z"}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/members/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": "pm-1", "role": 15, "member": {"id": MEMBER, "display_name": "alice"}}
+        ])))
+        .mount(&mock_server)
+        .await;
+    mount_worklogs(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["issues", "worklog", "list", ISSUE, "-p", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("alice") && !stdout.contains(MEMBER),
+        "{stdout}"
+    );
+    assert!(stdout.contains("Total: 2h"), "{stdout}");
+}
+
+#[tokio::test]
+async fn worklog_report_csv_sums_per_member_in_range() {
+    let mock_server = MockServer::start().await;
+    mount_worklogs(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "worklog",
+            "report",
+            "--from",
+            "2025-01-01",
+            "--to",
+            "2025-01-31",
+            "--csv",
+        ])
+        .assert()
+        .success()
+        .stdout("member,minutes,hours,entries\nalice,120,2.00,2\n");
+}
+
+#[tokio::test]
+async fn worklog_report_by_issue_json() {
+    let mock_server = MockServer::start().await;
+    mount_worklogs(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args([
+            "--json",
+            "worklog",
+            "report",
+            "-p",
+            PROJECT,
+            "--by",
+            "issue",
+            "--to",
+            "2024-12-31",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["total_minutes"], 60);
+    assert_eq!(report["rows"][0]["issue"], "WEB-7 Fix login");
+}
+
+#[tokio::test]
+async fn worklog_report_includes_projects_past_first_page() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/projects/"))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": PROJECT, "identifier": "WEB", "name": "Website"}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/projects/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "other", "identifier": "OPS", "name": "Operations"}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/projects/other/issues/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"results": []})))
+        .mount(&mock_server)
+        .await;
+    mount_worklogs(&mock_server).await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "worklog",
+            "report",
+            "-p",
+            PROJECT,
+            "--from",
+            "2025-01-01",
+            "--csv",
+        ])
+        .assert()
+        .success()
+        .stdout("member,minutes,hours,entries\nalice,120,2.00,2\n");
+}
+
+#[test]
+fn worklog_report_rejects_inverted_range() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args([
+            "worklog",
+            "report",
+            "--from",
+            "2025-02-01",
+            "--to",
+            "2025-01-01",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is after --to"));
+}
+
+// ── Intake ──
+
+#[tokio::test]
+async fn intake_list_filters_pending_by_default() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/intake-issues/"
+        )))
+        .and(query_param("status", "-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{
+                "id": "i1", "issue": ISSUE, "status": -2,
+                "created_at": "2025-01-01T10:00:00Z",
+                "issue_detail": {"name": "Checkout crashes on Safari", "priority": "high"}
+            }],
+            "next_page_results": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["intake", "list", "-p", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Checkout crashes on Safari"));
+    assert!(stdout.contains("pending"));
+    assert!(stdout.contains(ISSUE));
+}
+
+#[tokio::test]
+async fn intake_create_wraps_issue_fields() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/intake-issues/"
+        )))
+        .and(body_json(serde_json::json!({
+            "issue": {"name": "Broken link", "description_html": "<p>On pricing</p>", "priority": "low"}
+        })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({"id": "i1", "issue": ISSUE, "status": -2})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "intake",
+            "create",
+            "-p",
+            PROJECT,
+            "--title",
+            "Broken link",
+            "--description",
+            "<p>On pricing</p>",
+            "--priority",
+            "low",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created intake issue Broken link"));
+}
+
+async fn mount_intake_patch(mock_server: &MockServer, body: serde_json::Value) {
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/intake-issues/{ISSUE}/"
+        )))
+        .and(body_json(body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "i1", "issue": ISSUE, "issue_detail": {"name": "Broken link"}
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn intake_accept_and_decline_set_status() {
+    for (action, status, verb) in [("accept", 1, "Accepted"), ("decline", -1, "Declined")] {
+        let mock_server = MockServer::start().await;
+        mount_intake_patch(&mock_server, serde_json::json!({"status": status})).await;
+
+        plane_cmd_with(&mock_server.uri())
+            .args(["intake", action, ISSUE, "-p", PROJECT])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(format!("{verb} Broken link")));
+    }
+}
+
+#[tokio::test]
+async fn intake_mark_duplicate_resolves_original() {
+    let mock_server = MockServer::start().await;
+    let original = "0a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d";
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/issues/WEB-90/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": original, "project": PROJECT})),
+        )
+        .mount(&mock_server)
+        .await;
+    mount_intake_patch(
+        &mock_server,
+        serde_json::json!({"status": 2, "duplicate_to": original}),
+    )
+    .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "intake",
+            "mark-duplicate",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--of",
+            "WEB-90",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Marked as duplicate Broken link"));
+}
+
+#[tokio::test]
+async fn intake_snooze_sends_snoozed_till() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/intake-issues/{ISSUE}/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "i1", "issue_detail": {"name": "Broken link"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "intake",
+            "snooze",
+            ISSUE,
+            "-p",
+            PROJECT,
+            "--until",
+            "2025-03-01",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Snoozed Broken link until 2025-03-01",
+        ));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["status"], 0);
+    assert!(
+        body["snoozed_till"]
+            .as_str()
+            .unwrap()
+            .starts_with("2025-03-01T00:00:00")
+    );
+}
+
+// ── Project management ──
+
+#[tokio::test]
+async fn projects_create_sends_fields_and_resolves_lead() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/members/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": MEMBER, "display_name": "alice", "email": "alice@example.com"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/workspaces/test-ws/projects/"))
+        .and(body_json(serde_json::json!({
+            "name": "Acme Portal",
+            "identifier": "ACME",
+            "network": 0,
+            "project_lead": MEMBER,
+            "cycle_view": true,
+            "page_view": false
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "id": PROJECT, "name": "Acme Portal", "identifier": "ACME"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "projects",
+            "create",
+            "--name",
+            "Acme Portal",
+            "--identifier",
+            "acme",
+            "--network",
+            "secret",
+            "--lead",
+            "alice@example.com",
+            "--cycles",
+            "true",
+            "--pages",
+            "false",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Created project Acme Portal (ACME)",
+        ));
+}
+
+#[tokio::test]
+async fn projects_get_shows_lead_and_features() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": PROJECT, "name": "Website", "identifier": "WEB", "network": 2,
+            "project_lead": MEMBER, "default_assignee": null,
+            "cycle_view": true, "module_view": false, "page_view": true
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([{"id": MEMBER, "display_name": "alice"}])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["projects", "get", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Website"));
+    assert!(stdout.contains("public"));
+    assert!(stdout.contains("alice"));
+    assert!(stdout.contains("cycles, pages"), "{stdout}");
+}
+
+#[tokio::test]
+async fn projects_get_names_lead_from_later_membership_page() {
+    let mock_server = MockServer::start().await;
+    let base = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}");
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": PROJECT, "name": "Website", "identifier": "WEB", "project_lead": MEMBER
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/members/")))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "pm-2", "member": {"id": MEMBER, "display_name": "alice"}}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/members/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "pm-1", "member": {"id": "u1", "display_name": "bob"}}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["projects", "get", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("alice").and(predicate::str::contains(MEMBER).not()));
+}
+
+#[test]
+fn projects_update_requires_a_field() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args(["projects", "update", PROJECT])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("nothing to update"));
+}
+
+#[tokio::test]
+async fn projects_update_toggles_modules() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/"
+        )))
+        .and(body_json(serde_json::json!({"module_view": true})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": PROJECT, "name": "Website", "module_view": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["projects", "update", PROJECT, "--modules", "true"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Updated project Website"));
+}
+
+#[tokio::test]
+async fn projects_archive_and_unarchive() {
+    let mock_server = MockServer::start().await;
+    let archive = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}/archive/");
+    Mock::given(method("POST"))
+        .and(path(archive.as_str()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(archive.as_str()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["projects", "archive", PROJECT, "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Archived project"));
+    plane_cmd_with(&mock_server.uri())
+        .args(["projects", "unarchive", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unarchived project"));
+}
+
+#[test]
+fn projects_unarchive_requires_id() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args(["projects", "unarchive", "WEB"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass the project ID"));
+}
+
+// ── State management ──
+
+#[tokio::test]
+async fn states_create_sends_color_group_and_sequence() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/"
+        )))
+        .and(body_json(serde_json::json!({
+            "name": "Review", "color": "#3a86ff", "group": "started", "sequence": 35000.0
+        })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({"id": STATE, "name": "Review"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "states",
+            "create",
+            "-p",
+            PROJECT,
+            "--name",
+            "Review",
+            "--color",
+            "3A86FF",
+            "--group",
+            "started",
+            "--sequence",
+            "35000",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created state Review (started)"));
+}
+
+#[test]
+fn states_create_rejects_bad_color() {
+    plane_cmd_with("http://127.0.0.1:1")
+        .args([
+            "states", "create", "-p", PROJECT, "--name", "QA", "--color", "blue", "--group",
+            "started",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid color 'blue'"));
+}
+
+#[tokio::test]
+async fn states_update_resolves_state_by_name() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": STATE, "name": "Todo", "group": "unstarted"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/{STATE}/"
+        )))
+        .and(body_json(serde_json::json!({"sequence": 15000.0})))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({"id": STATE, "name": "Todo", "sequence": 15000.0}),
+            ),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "states",
+            "update",
+            "todo",
+            "-p",
+            PROJECT,
+            "--sequence",
+            "15000",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Updated state Todo"));
+}
+
+const OTHER_STATE: &str = "6d5c4b3a-2f1e-4d0c-9b8a-7f6e5d4c3b2a";
+
+async fn mount_state_issues(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(query_param("state", STATE))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": ISSUE, "state": STATE}],
+            "next_page_results": false
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn states_delete_refuses_when_state_has_issues() {
+    let mock_server = MockServer::start().await;
+    mount_state_issues(&mock_server).await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["states", "delete", STATE, "-p", PROJECT, "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("still has 1 issue(s)"))
+        .stderr(predicate::str::contains("--move-to"));
+}
+
+#[tokio::test]
+async fn states_delete_moves_issues_first() {
+    let mock_server = MockServer::start().await;
+    mount_state_issues(&mock_server).await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/{ISSUE}/"
+        )))
+        .and(body_json(serde_json::json!({"state": OTHER_STATE})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": ISSUE})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/states/{STATE}/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "states",
+            "delete",
+            STATE,
+            "-p",
+            PROJECT,
+            "--move-to",
+            OTHER_STATE,
+            "--yes",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Moved 1 issue(s)"))
+        .stdout(predicate::str::contains("Deleted state"));
+}
+
+// ── Label management ──
+
+#[tokio::test]
+async fn labels_list_renders_groups_as_tree() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [
+                {"id": "l3", "name": "low", "parent": "l1", "color": "#8ecae6"},
+                {"id": "l1", "name": "Severity", "parent": null, "color": "#e63946"},
+                {"id": "l4", "name": "bug", "parent": null},
+                {"id": "l2", "name": "high", "parent": "l1", "color": "#e63946"}
+            ],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["labels", "list", "-p", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let bug = stdout.find("bug").unwrap();
+    let severity = stdout.find("Severity").unwrap();
+    let high = stdout.find("├─ high").unwrap();
+    let low = stdout.find("└─ low").unwrap();
+    assert!(bug < severity && severity < high && high < low, "{stdout}");
+}
+
+#[tokio::test]
+async fn labels_create_nests_under_parent() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": LABEL, "name": "Severity"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/"
+        )))
+        .and(body_json(serde_json::json!({
+            "name": "critical", "color": "#d00000", "parent": LABEL
+        })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({"id": "l9", "name": "critical"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "labels", "create", "-p", PROJECT, "--name", "critical", "--color", "#D00000",
+            "--parent", "severity",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created label critical"));
+}
+
+#[tokio::test]
+async fn labels_update_no_parent_sends_null() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/{LABEL}/"
+        )))
+        .and(body_json(serde_json::json!({"parent": null})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": LABEL, "name": "critical"})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["labels", "update", LABEL, "-p", PROJECT, "--no-parent"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Updated label critical"));
+}
+
+#[tokio::test]
+async fn labels_delete_by_id() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/labels/{LABEL}/"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["labels", "delete", LABEL, "-p", PROJECT, "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Deleted label"));
+}
+
+// ── Member management ──
+
+#[tokio::test]
+async fn members_list_shows_email_and_role() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": MEMBER, "display_name": "alice", "email": "alice@example.com", "role": 20},
+            {"id": "m2", "display_name": "bob", "email": "bob@example.com", "role": 5}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["members", "list", "-p", PROJECT])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Email") && stdout.contains("Role"));
+    assert!(stdout.contains("alice@example.com"));
+    assert!(stdout.contains("admin"));
+    assert!(stdout.contains("guest"));
+}
+
+#[tokio::test]
+async fn members_list_all_follows_pages_of_memberships() {
+    let mock_server = MockServer::start().await;
+    let members = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}/members/");
+    Mock::given(method("GET"))
+        .and(path(members.as_str()))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "pm-2", "member": "u-bob", "role": 5,
+                         "display_name": "bob", "email": "bob@example.com"}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(members.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "pm-1", "role": 20,
+                         "member": {"id": MEMBER, "display_name": "alice", "email": "alice@example.com"}}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["members", "list", "-p", PROJECT, "--all"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("alice") && stdout.contains(MEMBER),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("bob@example.com") && stdout.contains("u-bob"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("guest"), "{stdout}");
+    assert!(!stdout.contains("pm-1"), "{stdout}");
+}
+
+#[tokio::test]
+async fn members_add_resolves_workspace_member() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/members/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": MEMBER, "display_name": "alice", "email": "alice@example.com"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .and(body_json(serde_json::json!({"member": MEMBER, "role": 20})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "pm1"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "members",
+            "add",
+            "alice@example.com",
+            "-p",
+            PROJECT,
+            "--role",
+            "admin",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("as admin"));
+}
+
+#[tokio::test]
+async fn members_set_role_and_remove() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/members/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": "pm-1",
+                "role": 15,
+                "member": {"id": MEMBER, "display_name": "alice", "email": "alice@example.com"}
+            }
+        ])))
+        .mount(&mock_server)
+        .await;
+    let member_path = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}/members/pm-1/");
+    Mock::given(method("PATCH"))
+        .and(path(member_path.as_str()))
+        .and(body_json(serde_json::json!({"role": 5})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"role": 5})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(member_path.as_str()))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args([
+            "members", "set-role", "alice", "-p", PROJECT, "--role", "guest",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is now guest"));
+    plane_cmd_with(&mock_server.uri())
+        .args(["members", "remove", MEMBER, "-p", PROJECT, "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed"));
+}
+
+#[tokio::test]
+async fn workspace_members_list_table() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/members/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {"id": MEMBER, "display_name": "alice", "email": "alice@example.com", "role": 15},
+            {"id": "m3", "display_name": "carol", "email": "carol@example.com", "role": 20}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["workspace", "members", "list"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("carol@example.com")
+                .and(predicate::str::contains("member"))
+                .and(predicate::str::contains("admin")),
+        );
+}
+
+// ── Apply ──
+
+async fn mount_apply_project(mock_server: &MockServer) {
+    for (kind, body) in [
+        (
+            "states",
+            serde_json::json!({
+                "results": [
+                    {"id": STATE, "name": "Todo", "group": "unstarted", "color": "#aaaaaa"},
+                    {"id": OTHER_STATE, "name": "Legacy", "group": "started", "color": "#111111"}
+                ],
+                "next_page_results": false
+            }),
+        ),
+        (
+            "labels",
+            serde_json::json!({"results": [], "next_page_results": false}),
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/api/v1/workspaces/test-ws/projects/{PROJECT}/{kind}/"
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [],
+            "next_page_results": false
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+fn write_manifest(contents: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+    std::io::Write::write_all(&mut file, contents.as_bytes()).unwrap();
+    file
+}
+
+const APPLY_MANIFEST: &str = "\
+states:
+  - {name: Todo, group: unstarted, color: '#AAAAAA'}
+  - {name: Review, group: started, color: '#3a86ff'}
+labels:
+  - {name: critical, parent: Severity, color: '#d00000'}
+  - {name: Severity}
+";
+
+#[tokio::test]
+async fn apply_members_reads_every_page() {
+    let mock_server = MockServer::start().await;
+    let base = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/members/"))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": MEMBER, "display_name": "carol", "email": "carol@example.com"}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/workspaces/test-ws/members/"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "u1", "display_name": "alice", "email": "alice@example.com"}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/members/")))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "pm-3", "role": 5, "member": {"id": MEMBER, "display_name": "carol"}}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/members/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "pm-1", "role": 20, "member": {"id": "u1", "display_name": "alice"}}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("{base}/members/pm-3/")))
+        .and(body_json(serde_json::json!({"role": 15})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"id": "pm-3"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    for verb in ["POST", "DELETE"] {
+        Mock::given(method(verb))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+    }
+    let manifest = write_manifest(
+        "members:\n  - {member: alice, role: admin}\n  - {member: carol@example.com}\n",
+    );
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["apply", "-f"])
+        .arg(manifest.path())
+        .args(["-p", PROJECT, "--prune", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Applied 1 change(s)"));
+}
+
+/// Puts an issue in the `Legacy` state that `APPLY_MANIFEST` leaves out.
+async fn mount_legacy_issue(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v1/workspaces/test-ws/projects/{PROJECT}/issues/"
+        )))
+        .and(query_param("state", OTHER_STATE))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": ISSUE, "state": OTHER_STATE}],
+            "next_page_results": false
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn apply_prune_refuses_state_with_issues_before_writing() {
+    let mock_server = MockServer::start().await;
+    mount_legacy_issue(&mock_server).await;
+    mount_apply_project(&mock_server).await;
+    for verb in ["POST", "PATCH", "DELETE"] {
+        Mock::given(method(verb))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+    }
+    let manifest = write_manifest(APPLY_MANIFEST);
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["apply", "-f"])
+        .arg(manifest.path())
+        .args(["-p", PROJECT, "--prune", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "state 'Legacy' still has 1 issue(s)",
+        ));
+}
+
+#[tokio::test]
+async fn apply_dry_run_warns_about_state_with_issues() {
+    let mock_server = MockServer::start().await;
+    mount_legacy_issue(&mock_server).await;
+    mount_apply_project(&mock_server).await;
+    let manifest = write_manifest(APPLY_MANIFEST);
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["apply", "-f"])
+        .arg(manifest.path())
+        .args(["-p", PROJECT, "--prune", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("- state Legacy")
+                .and(predicate::str::contains("Plan: 3 to create"))
+                .and(predicate::str::contains(
+                    "warning: state 'Legacy' still has 1 issue(s)",
+                )),
+        );
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["--json", "apply", "-f"])
+        .arg(manifest.path())
+        .args(["-p", PROJECT, "--prune", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["warnings"].as_array().map(Vec::len), Some(1));
+}
+
+#[tokio::test]
+async fn apply_dry_run_prints_plan_without_writing() {
+    let mock_server = MockServer::start().await;
+    mount_apply_project(&mock_server).await;
+    for verb in ["POST", "PATCH", "DELETE"] {
+        Mock::given(method(verb))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+    }
+    let manifest = write_manifest(APPLY_MANIFEST);
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["apply", "-f"])
+        .arg(manifest.path())
+        .args(["-p", PROJECT, "--dry-run", "--prune"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("+ state Review"), "{stdout}");
+    assert!(stdout.contains("- state Legacy"), "{stdout}");
+    assert!(stdout.contains("parent: Severity"), "{stdout}");
+    assert!(!stdout.contains("state Todo"), "{stdout}");
+    assert!(stdout.contains("Plan: 3 to create, 0 to update, 1 to delete."));
+}
+
+#[tokio::test]
+async fn apply_creates_parent_labels_before_children() {
+    let mock_server = MockServer::start().await;
+    mount_apply_project(&mock_server).await;
+    let base = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}");
+    Mock::given(method("POST"))
+        .and(path(format!("{base}/states/")))
+        .and(body_json(serde_json::json!({
+            "name": "Review", "group": "started", "color": "#3a86ff"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "s9"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("{base}/labels/")))
+        .and(body_json(serde_json::json!({"name": "Severity"})))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": LABEL})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("{base}/labels/")))
+        .and(body_json(serde_json::json!({
+            "name": "critical", "color": "#d00000", "parent": LABEL
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({"id": "l9"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let manifest = write_manifest(APPLY_MANIFEST);
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["apply", "-f"])
+        .arg(manifest.path())
+        .args(["-p", PROJECT])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Applied 3 change(s)"));
+}
+
+#[tokio::test]
+async fn apply_prune_requires_confirmation() {
+    let mock_server = MockServer::start().await;
+    mount_apply_project(&mock_server).await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    let manifest = write_manifest("states:\n  - {name: Todo, group: unstarted}\n");
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["apply", "-f"])
+        .arg(manifest.path())
+        .args(["-p", PROJECT, "--prune"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --yes"));
+}
+
+#[tokio::test]
+async fn apply_json_reports_plan() {
+    let mock_server = MockServer::start().await;
+    mount_apply_project(&mock_server).await;
+    let manifest = write_manifest(&format!(
+        "project: {PROJECT}\nstates:\n  - {{name: Todo, group: unstarted, color: '#aaaaaa'}}\n"
+    ));
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["--json", "apply", "-f"])
+        .arg(manifest.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["project"], PROJECT);
+    assert_eq!(report["changes"], serde_json::json!([]));
+    assert_eq!(report["applied"], 0);
+}
+
+#[test]
+fn apply_rejects_invalid_manifest() {
+    let manifest = write_manifest("states:\n  - {name: QA, group: testing}\n");
+    plane_cmd_with("http://127.0.0.1:1")
+        .args(["apply", "-p", PROJECT, "-f"])
+        .arg(manifest.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown group 'testing'"));
+}
+
+#[test]
+fn apply_requires_a_project() {
+    let manifest = write_manifest("labels: []\n");
+    plane_cmd_with("http://127.0.0.1:1")
+        .args(["apply", "-f"])
+        .arg(manifest.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no project"));
+}
+
+// ── Export ──
+
+async fn mount_export_project(mock_server: &MockServer) {
+    let base = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}");
+    let page = |items: serde_json::Value| serde_json::json!({"results": items, "next_page_results": false});
+    for (suffix, body) in [
+        (
+            String::new(),
+            serde_json::json!({"id": PROJECT, "identifier": "WEB", "name": "Website"}),
+        ),
+        (
+            "states/".to_string(),
+            page(serde_json::json!([{"id": STATE, "name": "Todo"}])),
+        ),
+        (
+            "labels/".to_string(),
+            page(serde_json::json!([{"id": LABEL, "name": "bug"}])),
+        ),
+        (
+            "members/".to_string(),
+            serde_json::json!([{"id": MEMBER, "display_name": "alice"}]),
+        ),
+        (
+            "cycles/".to_string(),
+            page(serde_json::json!([{"id": CYCLE, "name": "Sprint 1"}])),
+        ),
+        ("modules/".to_string(), page(serde_json::json!([]))),
+        (
+            "issues/".to_string(),
+            page(serde_json::json!([{"id": ISSUE, "name": "Fix login", "sequence_id": 1}])),
+        ),
+        (
+            format!("issues/{ISSUE}/comments/"),
+            page(serde_json::json!([{"id": "c1", "comment_html": "<p>Done</p>"}])),
+        ),
+        (
+            format!("issues/{ISSUE}/links/"),
+            page(serde_json::json!([{"id": "k1", "url": "https://example.com/pr/1"}])),
+        ),
+        (
+            format!("issues/{ISSUE}/issue-relation/"),
+            serde_json::json!({"blocking": [], "blocked_by": []}),
+        ),
+        (
+            format!("cycles/{CYCLE}/cycle-issues/"),
+            page(serde_json::json!([{"id": ISSUE, "name": "Fix login"}])),
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!("{base}/{suffix}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(mock_server)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn export_json_archive_with_manifest() {
+    let mock_server = MockServer::start().await;
+    mount_export_project(&mock_server).await;
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("web.json");
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["export", "-p", PROJECT, "-o"])
+        .arg(&out)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported WEB"))
+        .stdout(predicate::str::contains("1 issues, 1 comments, 1 links"));
+
+    let archive: serde_json::Value = serde_json::from_slice(&std::fs::read(&out).unwrap()).unwrap();
+    assert_eq!(archive["manifest"]["format"], "plane-cli-export");
+    assert_eq!(archive["manifest"]["version"], 1);
+    assert_eq!(archive["manifest"]["project"]["identifier"], "WEB");
+    assert_eq!(archive["manifest"]["counts"]["issues"], 1);
+    assert_eq!(archive["issues"][0]["name"], "Fix login");
+    assert_eq!(archive["comments"][ISSUE][0]["id"], "c1");
+    assert_eq!(
+        archive["links"][ISSUE][0]["url"],
+        "https://example.com/pr/1"
+    );
+    assert_eq!(archive["cycle_issues"][CYCLE], serde_json::json!([ISSUE]));
+    assert!(archive["relations"][ISSUE].is_object());
+}
+
+#[tokio::test]
+async fn export_tar_has_one_file_per_collection() {
+    let mock_server = MockServer::start().await;
+    mount_export_project(&mock_server).await;
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("web.tar");
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["export", "-p", PROJECT, "--format", "tar", "-o"])
+        .arg(&out)
+        .assert()
+        .success();
+
+    let mut archive = tar::Archive::new(std::fs::File::open(&out).unwrap());
+    let mut files = std::collections::HashMap::new();
+    for entry in archive.entries().unwrap() {
+        let mut entry = entry.unwrap();
+        let name = entry.path().unwrap().display().to_string();
+        let mut contents = String::new();
+        std::io::Read::read_to_string(&mut entry, &mut contents).unwrap();
+        files.insert(name, contents);
+    }
+    let manifest = files
+        .iter()
+        .find(|(name, _)| name.ends_with("/manifest.json"))
+        .map(|(_, contents)| serde_json::from_str::<serde_json::Value>(contents).unwrap())
+        .unwrap();
+    assert_eq!(manifest["version"], 1);
+    for collection in ["project", "states", "issues", "comments", "cycle_issues"] {
+        assert!(
+            files
+                .keys()
+                .any(|name| name.starts_with("WEB-")
+                    && name.ends_with(&format!("/{collection}.json"))),
+            "missing {collection}: {:?}",
+            files.keys()
+        );
+    }
+}
+
+#[tokio::test]
+async fn export_default_path_ignores_directories_in_identifier() {
+    let mock_server = MockServer::start().await;
+    let base = format!("/api/v1/workspaces/test-ws/projects/{PROJECT}");
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"id": PROJECT, "identifier": "../evil", "name": "Website"}),
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/issues/{ISSUE}/links/")))
+        .and(query_param("cursor", "100:1:0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "k2", "url": "https://example.com/pr/2"}],
+            "next_page_results": false
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("{base}/issues/{ISSUE}/links/")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "results": [{"id": "k1", "url": "https://example.com/pr/1"}],
+            "next_cursor": "100:1:0",
+            "next_page_results": true
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_export_project(&mock_server).await;
+    let dir = tempfile::tempdir().unwrap();
+    let cwd = dir.path().join("work");
+    std::fs::create_dir(&cwd).unwrap();
+
+    plane_cmd_with(&mock_server.uri())
+        .current_dir(&cwd)
+        .args(["export", "-p", PROJECT])
+        .assert()
+        .success();
+
+    assert!(
+        std::fs::read_dir(dir.path())
+            .unwrap()
+            .all(|e| e.unwrap().file_name() == "work")
+    );
+    let entries: Vec<_> = std::fs::read_dir(&cwd)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .collect();
+    assert_eq!(entries.len(), 1, "{entries:?}");
+    let name = entries[0]
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned();
+    assert!(
+        name.starts_with("evil-") && name.ends_with(".json"),
+        "{name}"
+    );
+    let archive: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(&entries[0]).unwrap()).unwrap();
+    assert_eq!(archive["manifest"]["counts"]["links"], 2);
+    assert_eq!(archive["links"][ISSUE][1]["id"], "k2");
+}
+
+#[tokio::test]
+async fn export_failed_write_leaves_no_part_file() {
+    let mock_server = MockServer::start().await;
+    mount_export_project(&mock_server).await;
+    let dir = tempfile::tempdir().unwrap();
+    // A non-empty directory under the target name makes the final rename fail.
+    let out = dir.path().join("web.json");
+    std::fs::create_dir(&out).unwrap();
+    std::fs::write(out.join("keep"), "").unwrap();
+
+    plane_cmd_with(&mock_server.uri())
+        .args(["export", "-p", PROJECT, "-o"])
+        .arg(&out)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("failed to write"));
+
+    let names: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .collect();
+    assert_eq!(names, ["web.json"]);
+    assert!(out.join("keep").exists());
+}
+
+#[tokio::test]
+async fn export_to_stdout() {
+    let mock_server = MockServer::start().await;
+    mount_export_project(&mock_server).await;
+
+    let output = plane_cmd_with(&mock_server.uri())
+        .args(["export", "-p", PROJECT, "-o", "-"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let archive: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(archive["states"][0]["name"], "Todo");
+    assert_eq!(archive["members"][0]["display_name"], "alice");
 }